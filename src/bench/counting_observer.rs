@@ -0,0 +1,153 @@
+use crate::observer::{
+    event::{Event, Terminated},
+    Observer,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+#[derive(Debug)]
+struct Timing {
+    first_value: Option<Instant>,
+    last_event: Option<Instant>,
+}
+
+/**
+An observer that only counts the values it receives, and measures the time from the first value to the last event for the throughput.
+Clones share the counters, so a clone can be subscribed and the original read afterwards.
+
+# Example
+```rust
+use rx_rust::bench::counting_observer::CountingObserver;
+use rx_rust::bench::synthetic_load::SyntheticLoad;
+use rx_rust::observable::Observable;
+let counter = CountingObserver::new();
+SyntheticLoad::new(1000, 64).subscribe(counter.clone());
+assert_eq!(counter.count(), 1000);
+assert!(counter.is_completed());
+println!("{:.0} values/s", counter.throughput());
+```
+*/
+#[derive(Debug, Clone)]
+pub struct CountingObserver {
+    count: Arc<AtomicUsize>,
+    completed: Arc<AtomicBool>,
+    terminated: Arc<AtomicBool>,
+    timing: Arc<Mutex<Timing>>,
+}
+
+impl CountingObserver {
+    pub fn new() -> CountingObserver {
+        CountingObserver {
+            count: Arc::new(AtomicUsize::new(0)),
+            completed: Arc::new(AtomicBool::new(false)),
+            terminated: Arc::new(AtomicBool::new(false)),
+            timing: Arc::new(Mutex::new(Timing {
+                first_value: None,
+                last_event: None,
+            })),
+        }
+    }
+
+    /// The number of values received.
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Get whether the completed event is received.
+    pub fn is_completed(&self) -> bool {
+        self.completed.load(Ordering::SeqCst)
+    }
+
+    /// The time from the first value to the last event.
+    pub fn elapsed(&self) -> Duration {
+        let timing = self.timing.lock().unwrap();
+        match (timing.first_value, timing.last_event) {
+            (Some(first_value), Some(last_event)) => last_event - first_value,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// The values received per second. It's not finite when the elapsed time is zero, e.g. for a single value.
+    pub fn throughput(&self) -> f64 {
+        self.count() as f64 / self.elapsed().as_secs_f64()
+    }
+}
+
+impl Default for CountingObserver {
+    fn default() -> Self {
+        CountingObserver::new()
+    }
+}
+
+impl<T, E> Observer<T, E> for CountingObserver {
+    fn on(&self, event: Event<T, E>) {
+        let now = Instant::now();
+        let mut timing = self.timing.lock().unwrap();
+        if let Event::Next(_) = event {
+            timing.first_value.get_or_insert(now);
+        }
+        timing.last_event = Some(now);
+        drop(timing);
+        match event {
+            Event::Next(_) => {
+                self.count.fetch_add(1, Ordering::SeqCst);
+            }
+            Event::Terminated(Terminated::Completed) => {
+                self.completed.store(true, Ordering::SeqCst);
+            }
+            Event::Terminated(_) => {}
+        }
+    }
+
+    fn terminated(&self) -> bool {
+        self.terminated.load(Ordering::SeqCst)
+    }
+
+    fn set_terminated(&self, terminated: bool) {
+        self.terminated.store(terminated, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bench::synthetic_load::SyntheticLoad, observable::Observable, operators::map::Map,
+    };
+
+    #[test]
+    fn test_count() {
+        let counter = CountingObserver::new();
+        let observable = Map::new(SyntheticLoad::new(10, 8), |payload: Vec<u8>| payload.len());
+        observable.subscribe(counter.clone());
+        assert_eq!(counter.count(), 10);
+        assert!(counter.is_completed());
+        assert!(counter.throughput() > 0.0);
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let counter = CountingObserver::new();
+        Observer::<i32, String>::notify_if_unterminated(&counter, Event::Next(1));
+        assert_eq!(counter.count(), 1);
+        assert!(!counter.is_completed());
+        assert_eq!(counter.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_terminated_without_values() {
+        let counter = CountingObserver::new();
+        Observer::<i32, String>::notify_if_unterminated(
+            &counter,
+            Event::Terminated(Terminated::Completed),
+        );
+        assert_eq!(counter.count(), 0);
+        assert!(counter.is_completed());
+        assert!(counter.timing.lock().unwrap().first_value.is_none());
+    }
+}
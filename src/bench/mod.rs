@@ -0,0 +1,4 @@
+//! Helpers for benchmarking operator chains, e.g. inside a criterion harness. Enabled by the `bench` feature.
+pub mod counting_observer;
+pub mod noop_scheduler;
+pub mod synthetic_load;
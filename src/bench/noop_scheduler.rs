@@ -0,0 +1,147 @@
+use crate::{scheduler::Scheduler, utils::disposal::Disposal};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+type Task = Box<dyn FnOnce() + Send + 'static>;
+
+thread_local! {
+    /// The tasks scheduled by the running task, `None` if no task is running on this thread.
+    static QUEUE: RefCell<Option<VecDeque<Task>>> = const { RefCell::new(None) };
+}
+
+/// Clears the queue when the loop ends, also if a task panics.
+struct Running;
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        QUEUE.with(|queue| queue.borrow_mut().take());
+    }
+}
+
+/**
+A scheduler that runs each task on the calling thread immediately, ignoring the delay. It keeps timers and threads out of the measurements.
+A task scheduled while another task is running is queued and run after it, so a recurring source such as `Interval` loops instead of recursing. As the delays are ignored, `subscribe` doesn't return until such a source stops, so limit it, e.g. with `Synthetic::limit`.
+
+# Example
+```rust
+use rx_rust::bench::noop_scheduler::NoopScheduler;
+use rx_rust::scheduler::Scheduler;
+use std::time::Duration;
+let scheduler = NoopScheduler;
+scheduler.schedule(|| println!("Hello"), Some(Duration::from_secs(1)));
+```
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopScheduler;
+
+impl Scheduler for NoopScheduler {
+    fn schedule(
+        &self,
+        task: impl FnOnce() + Send + 'static,
+        _delay: Option<Duration>,
+    ) -> Disposal<impl FnOnce() + Send + 'static> {
+        let disposed = Arc::new(AtomicBool::new(false));
+        let task: Task = {
+            let disposed = disposed.clone();
+            Box::new(move || {
+                if !disposed.load(Ordering::SeqCst) {
+                    task();
+                }
+            })
+        };
+        let task = QUEUE.with(|queue| match queue.borrow_mut().as_mut() {
+            Some(queue) => {
+                queue.push_back(task);
+                None
+            }
+            None => Some(task),
+        });
+        if let Some(task) = task {
+            QUEUE.with(|queue| *queue.borrow_mut() = Some(VecDeque::new()));
+            let _running = Running;
+            task();
+            while let Some(task) =
+                QUEUE.with(|queue| queue.borrow_mut().as_mut().and_then(VecDeque::pop_front))
+            {
+                task();
+            }
+        }
+        Disposal::new(move || disposed.store(true, Ordering::SeqCst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn test_run_inline() {
+        let ran = Arc::new(AtomicBool::new(false));
+        let ran_cloned = ran.clone();
+        let disposal = NoopScheduler.schedule(
+            move || ran_cloned.store(true, Ordering::SeqCst),
+            Some(Duration::from_secs(60)),
+        );
+        assert!(ran.load(Ordering::SeqCst));
+        disposal.dispose();
+    }
+
+    #[test]
+    fn test_nested_in_order() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let order_cloned = order.clone();
+        NoopScheduler.schedule(
+            move || {
+                let order_nested = order_cloned.clone();
+                let disposal =
+                    NoopScheduler.schedule(move || order_nested.lock().unwrap().push(2), None);
+                std::mem::forget(disposal);
+                order_cloned.lock().unwrap().push(1);
+            },
+            None,
+        );
+        assert_eq!(*order.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_nested_disposed() {
+        let ran = Arc::new(AtomicBool::new(false));
+        let ran_cloned = ran.clone();
+        NoopScheduler.schedule(
+            move || {
+                let disposal =
+                    NoopScheduler.schedule(move || ran_cloned.store(true, Ordering::SeqCst), None);
+                disposal.dispose();
+            },
+            None,
+        );
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_limited_interval() {
+        use crate::{
+            bench::counting_observer::CountingObserver, observable::Observable,
+            operators::synthetic::Synthetic,
+        };
+        let counter = CountingObserver::new();
+        let subscription =
+            Synthetic::new(|_| Duration::from_millis(1), |index| index, NoopScheduler)
+                .limit(100_000)
+                .subscribe(counter.clone());
+        assert_eq!(counter.count(), 100_000);
+        assert!(counter.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{convert::Infallible, time::Duration};
+
+/**
+This is an observable that synchronously emits `count` payloads of `payload_size` bytes then completes. With `bursts`, the emitting thread pauses after every `burst_size` payloads.
+Each payload is filled with the low byte of its index, so the values can be checked cheaply.
+
+# Example
+```rust
+use rx_rust::bench::synthetic_load::SyntheticLoad;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use std::time::Duration;
+let observable = SyntheticLoad::new(1000, 64).bursts(100, Duration::from_micros(10));
+observable.subscribe_on_next(|payload| assert_eq!(payload.len(), 64));
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SyntheticLoad {
+    count: usize,
+    payload_size: usize,
+    burst: Option<(usize, Duration)>,
+}
+
+impl SyntheticLoad {
+    pub fn new(count: usize, payload_size: usize) -> SyntheticLoad {
+        SyntheticLoad {
+            count,
+            payload_size,
+            burst: None,
+        }
+    }
+
+    /// Pause for `pause` after every `burst_size` payloads. A `burst_size` of 0 means no pauses.
+    pub fn bursts(mut self, burst_size: usize, pause: Duration) -> SyntheticLoad {
+        self.burst = (burst_size > 0).then_some((burst_size, pause));
+        self
+    }
+}
+
+impl Observable<Vec<u8>, Infallible> for SyntheticLoad {
+    fn subscribe(self, observer: impl Observer<Vec<u8>, Infallible>) -> Subscription {
+        for index in 0..self.count {
+            if observer.terminated() {
+                break;
+            }
+            if let Some((burst_size, pause)) = self.burst {
+                if index > 0 && index % burst_size == 0 {
+                    std::thread::sleep(pause);
+                }
+            }
+            observer.notify_if_unterminated(Event::Next(vec![index as u8; self.payload_size]));
+        }
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+    use std::time::Instant;
+
+    #[test]
+    fn test_completed() {
+        let observable = SyntheticLoad::new(3, 2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![0, 0], vec![1, 1], vec![2, 2]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_bursts() {
+        let observable = SyntheticLoad::new(5, 1).bursts(2, Duration::from_millis(10));
+        let checker = CheckingObserver::new();
+        let start = Instant::now();
+        observable.subscribe(checker.clone());
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert!(checker.is_values_matched(&[vec![0], vec![1], vec![2], vec![3], vec![4]]));
+        assert!(checker.is_completed());
+    }
+}
@@ -1,7 +1,11 @@
 #![forbid(unsafe_code)]
+#[cfg(feature = "bench")]
+pub mod bench;
 pub mod observable;
 pub mod observer;
 pub mod operators;
 pub mod scheduler;
 pub mod subscription;
+pub mod testing;
+pub mod topology;
 pub mod utils;
@@ -0,0 +1,72 @@
+use super::Observable;
+use crate::{observer::Observer, subscription::Subscription};
+
+/**
+An observable that is one of two observable types with the same value and error types. It lets the branches of an `if`/`else` build differently typed operator chains without boxing.
+
+# Example
+```rust
+use rx_rust::observable::either_observable::EitherObservable;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::operators::just::Just;
+use rx_rust::operators::map::MappableObservable;
+let doubled = true;
+let observable = if doubled {
+    EitherObservable::Left(Just::new(333).map(|value| value * 2))
+} else {
+    EitherObservable::Right(Just::new(333))
+};
+observable.subscribe_on_next(|value| println!("{}", value));
+```
+*/
+#[derive(Debug, Clone)]
+pub enum EitherObservable<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<T, E, L, R> Observable<T, E> for EitherObservable<L, R>
+where
+    L: Observable<T, E>,
+    R: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        match self {
+            EitherObservable::Left(observable) => observable.subscribe(observer),
+            EitherObservable::Right(observable) => observable.subscribe(observer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{just::Just, map::MappableObservable},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn observable(doubled: bool) -> impl Observable<i32, std::convert::Infallible> {
+        if doubled {
+            EitherObservable::Left(Just::new(333).map(|value| value * 2))
+        } else {
+            EitherObservable::Right(Just::new(333))
+        }
+    }
+
+    #[test]
+    fn test_left() {
+        let checker = CheckingObserver::new();
+        observable(true).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[666]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_right() {
+        let checker = CheckingObserver::new();
+        observable(false).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+}
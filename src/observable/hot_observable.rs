@@ -0,0 +1,234 @@
+use super::Observable;
+use crate::{
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+struct HotState<T, E> {
+    next_id: u64,
+    observers: HashMap<u64, Arc<dyn Observer<T, E>>>,
+    terminated: Option<Terminated<E>>,
+}
+
+/**
+A handle that feeds the values and the terminated event to all the observers of a `HotObservable`.
+
+# Example
+```rust
+use rx_rust::observable::hot_observable::HotObservable;
+let (emitter, _observable) = HotObservable::<i32, String>::new();
+emitter.emit(333);
+emitter.complete();
+assert!(emitter.is_terminated());
+```
+*/
+pub struct HotEmitter<T, E> {
+    state: Arc<Mutex<HotState<T, E>>>,
+}
+
+impl<T, E> Clone for HotEmitter<T, E> {
+    fn clone(&self) -> Self {
+        HotEmitter {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T, E> HotEmitter<T, E>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    /// Emit the value to all the current observers. The value is dropped if there is no observer or the observable is terminated.
+    pub fn emit(&self, value: T) {
+        let observers: Vec<_> = {
+            let state = self.state.lock().unwrap();
+            if state.terminated.is_some() {
+                return;
+            }
+            state.observers.values().cloned().collect()
+        };
+        for observer in observers {
+            observer.notify_if_unterminated(Event::Next(value.clone()));
+        }
+    }
+
+    /// Complete all the current observers. Observers subscribing afterwards are completed immediately.
+    pub fn complete(&self) {
+        self.terminate(Terminated::Completed);
+    }
+
+    /// Send the error to all the current observers. Observers subscribing afterwards receive the error immediately.
+    pub fn error(&self, error: E) {
+        self.terminate(Terminated::Error(error));
+    }
+
+    fn terminate(&self, terminated: Terminated<E>) {
+        let observers: Vec<_> = {
+            let mut state = self.state.lock().unwrap();
+            if state.terminated.is_some() {
+                return;
+            }
+            state.terminated = Some(terminated.clone());
+            state
+                .observers
+                .drain()
+                .map(|(_, observer)| observer)
+                .collect()
+        };
+        for observer in observers {
+            observer.notify_if_unterminated(Event::Terminated(terminated.clone()));
+        }
+    }
+
+    /// Get whether `complete` or `error` has been called.
+    pub fn is_terminated(&self) -> bool {
+        self.state.lock().unwrap().terminated.is_some()
+    }
+
+    /// Get the number of the current observers.
+    pub fn observer_count(&self) -> usize {
+        self.state.lock().unwrap().observers.len()
+    }
+}
+
+/**
+This is an observable that is fed by its `HotEmitter`. Unlike a cold observable, subscribing doesn't start anything: each observer receives the values emitted while it is subscribed.
+The emitter and the observable are separate values, so the producer can keep the emitter and hand out only the observable.
+
+# Example
+```rust
+use rx_rust::observable::hot_observable::HotObservable;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let (emitter, observable) = HotObservable::<i32, String>::new();
+let subscription = observable.subscribe_on_next(|value| {
+    println!("{}", value);
+});
+emitter.emit(333);
+emitter.complete();
+```
+*/
+pub struct HotObservable<T, E> {
+    state: Arc<Mutex<HotState<T, E>>>,
+}
+
+impl<T, E> HotObservable<T, E> {
+    /// Create a hot observable and the emitter that feeds it.
+    pub fn new() -> (HotEmitter<T, E>, HotObservable<T, E>) {
+        let state = Arc::new(Mutex::new(HotState {
+            next_id: 0,
+            observers: HashMap::new(),
+            terminated: None,
+        }));
+        (
+            HotEmitter {
+                state: state.clone(),
+            },
+            HotObservable { state },
+        )
+    }
+}
+
+impl<T, E> Clone for HotObservable<T, E> {
+    fn clone(&self) -> Self {
+        HotObservable {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T, E> Observable<T, E> for HotObservable<T, E>
+where
+    T: Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let mut state = self.state.lock().unwrap();
+        if let Some(terminated) = state.terminated.clone() {
+            drop(state);
+            observer.notify_if_unterminated(Event::Terminated(terminated));
+            return Subscription::new_non_disposal_action(observer);
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.observers.insert(id, observer.clone());
+        drop(state);
+        let state = self.state.clone();
+        Subscription::new(observer, move || {
+            let observer = state.lock().unwrap().observers.remove(&id);
+            drop(observer);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+
+    #[test]
+    fn test_completed() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        emitter.emit(1);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(2);
+        emitter.emit(3);
+        assert!(checker.is_values_matched(&[2, 3]));
+        assert!(checker.is_unterminated());
+        emitter.complete();
+        assert!(checker.is_completed());
+        assert_eq!(emitter.observer_count(), 0);
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        emitter.error("error".to_owned());
+        emitter.emit(2);
+        emitter.complete();
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_subscribe_after_terminated() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        emitter.error("error".to_owned());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = observable.subscribe(checker2.clone());
+        emitter.emit(1);
+        subscription1.unsubscribe();
+        assert_eq!(emitter.observer_count(), 1);
+        emitter.emit(2);
+        assert!(checker1.is_values_matched(&[1]));
+        assert!(checker1.is_unsubscribed());
+        assert!(checker2.is_values_matched(&[1, 2]));
+        assert!(checker2.is_unterminated());
+        _ = subscription2; // keep the subscription alive
+    }
+}
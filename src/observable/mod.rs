@@ -1,3 +1,5 @@
+pub mod either_observable;
+pub mod hot_observable;
 pub mod observable_into_ext;
 pub mod observable_subscribe_ext;
 
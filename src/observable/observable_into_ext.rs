@@ -19,7 +19,6 @@ observable.subscribe_on_next(|value| {
 });
 ```
  */
-
 pub trait ObservableIntoExt<T, E> {
     /// Converts any type that implements `Observable` into `impl Observable<T, E>`.
     fn into_observable(self) -> impl Observable<T, E>;
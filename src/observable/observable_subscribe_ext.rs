@@ -1,8 +1,15 @@
 use super::Observable;
 use crate::{
-    observer::{anonymous_observer::AnonymousObserver, event::Event},
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+    },
     subscription::Subscription,
 };
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
 
 /// Extension trait for `Observable`
 pub trait ObservableSubscribeExt<T, E> {
@@ -42,6 +49,61 @@ pub trait ObservableSubscribeExt<T, E> {
     ```
     */
     fn subscribe_on_next(self, on_next: impl Fn(T) + Sync + Send + 'static) -> Subscription;
+
+    /**
+    Subscribes to the observable and extends the shared collection with each value.
+
+    # Example
+    ```rust
+    use rx_rust::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt, operators::just::Just,
+    };
+    use std::sync::{Arc, Mutex};
+    let values = Arc::new(Mutex::new(vec![1, 2]));
+    let observable = Just::new(3);
+    observable.collect_into(values.clone());
+    assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+    ```
+    */
+    fn collect_into<C>(self, collection: Arc<Mutex<C>>) -> Subscription
+    where
+        C: Extend<T> + Send + 'static;
+
+    /**
+    Subscribes to the observable and counts the values. The counter is updated as the values arrive.
+
+    # Example
+    ```rust
+    use rx_rust::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt, operators::just::Just,
+    };
+    use std::sync::atomic::Ordering;
+    let observable = Just::new(123);
+    let (subscription, count) = observable.subscribe_counted();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    ```
+    */
+    fn subscribe_counted(self) -> (Subscription, Arc<AtomicUsize>);
+
+    /**
+    Subscribes to the observable with the given `on_result` callback, which is called with `Ok(())` when the observable completes or `Err(error)` when it errors. The values are ignored, and the callback is not called when the subscription is unsubscribed.
+
+    # Example
+    ```rust
+    use rx_rust::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt, operators::just::Just,
+    };
+    let observable = Just::new(123);
+    observable.subscribe_result(|result| match result {
+        Ok(()) => println!("done"),
+        Err(error) => println!("failed: {:?}", error),
+    });
+    ```
+    */
+    fn subscribe_result(
+        self,
+        on_result: impl Fn(Result<(), E>) + Sync + Send + 'static,
+    ) -> Subscription;
 }
 
 impl<T, E, O> ObservableSubscribeExt<T, E> for O
@@ -62,13 +124,44 @@ where
             Event::Terminated(_) => {}
         })
     }
+
+    fn collect_into<C>(self, collection: Arc<Mutex<C>>) -> Subscription
+    where
+        C: Extend<T> + Send + 'static,
+    {
+        self.subscribe_on_next(move |value| {
+            collection.lock().unwrap().extend(std::iter::once(value));
+        })
+    }
+
+    fn subscribe_counted(self) -> (Subscription, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_cloned = count.clone();
+        let subscription = self.subscribe_on_next(move |_| {
+            count_cloned.fetch_add(1, Ordering::SeqCst);
+        });
+        (subscription, count)
+    }
+
+    fn subscribe_result(
+        self,
+        on_result: impl Fn(Result<(), E>) + Sync + Send + 'static,
+    ) -> Subscription {
+        self.subscribe_on_event(move |event| match event {
+            Event::Next(_) | Event::Terminated(Terminated::Unsubscribed) => {}
+            Event::Terminated(Terminated::Completed) => on_result(Ok(())),
+            Event::Terminated(Terminated::Error(error)) => on_result(Err(error)),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        observer::Observer, operators::just::Just, utils::checking_observer::CheckingObserver,
+        observer::Observer,
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
     };
 
     #[test]
@@ -94,4 +187,56 @@ mod tests {
         assert!(checker.is_values_matched(&[123]));
         assert!(checker.is_unterminated());
     }
+
+    #[test]
+    fn test_collect_into() {
+        let observable = Just::new(123);
+        let values = Arc::new(Mutex::new(vec![1, 2]));
+        observable.collect_into(values.clone());
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 123]);
+    }
+
+    #[test]
+    fn test_subscribe_counted() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let (subscription, count) = observable.subscribe_counted();
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_subscribe_result() {
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let results_cloned = results.clone();
+        Just::new(123).subscribe_result(move |result| results_cloned.lock().unwrap().push(result));
+        assert_eq!(*results.lock().unwrap(), vec![Ok(())]);
+
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let results_cloned = results.clone();
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        observable.subscribe_result(move |result| results_cloned.lock().unwrap().push(result));
+        assert_eq!(*results.lock().unwrap(), vec![Err("error".to_owned())]);
+    }
+
+    #[test]
+    fn test_subscribe_result_unsubscribed() {
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let results_cloned = results.clone();
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            Subscription::new_non_disposal_action(observer)
+        });
+        let subscription =
+            observable.subscribe_result(move |result| results_cloned.lock().unwrap().push(result));
+        subscription.unsubscribe();
+        assert!(results.lock().unwrap().is_empty());
+    }
 }
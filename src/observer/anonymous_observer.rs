@@ -18,7 +18,6 @@ let observer = AnonymousObserver::new(|e: Event<i32, Infallible>| {
 observable.subscribe(observer);
 ```
 */
-
 pub struct AnonymousObserver<F> {
     received_event: F,
     terminated: RwLock<bool>,
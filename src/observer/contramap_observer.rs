@@ -0,0 +1,159 @@
+use super::{event::Event, Observer};
+
+/// This is an observer that maps the values with a function before passing them to the inner observer.
+pub struct ContramapObserver<O, F> {
+    observer: O,
+    f: F,
+}
+
+impl<O, F> ContramapObserver<O, F> {
+    pub fn new(observer: O, f: F) -> ContramapObserver<O, F> {
+        ContramapObserver { observer, f }
+    }
+}
+
+impl<T, T0, E, O, F> Observer<T0, E> for ContramapObserver<O, F>
+where
+    O: Observer<T, E>,
+    F: Fn(T0) -> T + Sync + Send + 'static,
+{
+    fn on(&self, event: Event<T0, E>) {
+        self.observer.on(event.map_value(&self.f));
+    }
+
+    fn terminated(&self) -> bool {
+        self.observer.terminated()
+    }
+
+    fn set_terminated(&self, terminated: bool) {
+        self.observer.set_terminated(terminated);
+    }
+}
+
+/// This is an observer that maps the error with a function before passing it to the inner observer.
+pub struct ContramapErrObserver<O, F> {
+    observer: O,
+    f: F,
+}
+
+impl<O, F> ContramapErrObserver<O, F> {
+    pub fn new(observer: O, f: F) -> ContramapErrObserver<O, F> {
+        ContramapErrObserver { observer, f }
+    }
+}
+
+impl<T, E, E0, O, F> Observer<T, E0> for ContramapErrObserver<O, F>
+where
+    O: Observer<T, E>,
+    F: Fn(E0) -> E + Sync + Send + 'static,
+{
+    fn on(&self, event: Event<T, E0>) {
+        self.observer.on(event.map_error(&self.f));
+    }
+
+    fn terminated(&self) -> bool {
+        self.observer.terminated()
+    }
+
+    fn set_terminated(&self, terminated: bool) {
+        self.observer.set_terminated(terminated);
+    }
+}
+
+/// Make the `Observer` adaptable to other value and error types.
+pub trait ContramapObserverExt<T, E>: Observer<T, E> + Sized {
+    /**
+    Creates an observer of `T0` values, which maps each value with `f` and passes it to this observer. It adapts an existing sink to a differently typed pipeline without writing a wrapper struct.
+
+    # Example
+    ```rust
+    use rx_rust::observable::Observable;
+    use rx_rust::observer::anonymous_observer::AnonymousObserver;
+    use rx_rust::observer::contramap_observer::ContramapObserverExt;
+    use rx_rust::observer::event::Event;
+    use rx_rust::operators::just::Just;
+    use std::convert::Infallible;
+    let observer = AnonymousObserver::new(|event: Event<String, Infallible>| {
+        println!("{:?}", event);
+    });
+    Just::new(123).subscribe(observer.contramap(|value: i32| value.to_string()));
+    ```
+     */
+    fn contramap<T0>(self, f: impl Fn(T0) -> T + Sync + Send + 'static) -> impl Observer<T0, E> {
+        ContramapObserver::new(self, f)
+    }
+
+    /**
+    Creates an observer of `E0` errors, which maps the error with `f` and passes it to this observer.
+
+    # Example
+    ```rust
+    use rx_rust::observer::anonymous_observer::AnonymousObserver;
+    use rx_rust::observer::contramap_observer::ContramapObserverExt;
+    use rx_rust::observer::event::{Event, Terminated};
+    use rx_rust::observer::Observer;
+    let observer = AnonymousObserver::new(|event: Event<i32, String>| {
+        println!("{:?}", event);
+    });
+    let observer = observer.contramap_err(|error_code: i32| error_code.to_string());
+    observer.notify_if_unterminated(Event::Terminated(Terminated::Error(404)));
+    ```
+     */
+    fn contramap_err<E0>(
+        self,
+        f: impl Fn(E0) -> E + Sync + Send + 'static,
+    ) -> impl Observer<T, E0> {
+        ContramapErrObserver::new(self, f)
+    }
+}
+
+impl<T, E, O> ContramapObserverExt<T, E> for O where O: Observer<T, E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::Observable,
+        observer::event::Terminated,
+        operators::{create::Create, just::Just},
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_contramap() {
+        let checker = CheckingObserver::new();
+        Just::new(123).subscribe(checker.clone().contramap(|value: i32| value.to_string()));
+        assert!(checker.is_values_matched(&["123".to_owned()]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_contramap_err() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, i32>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Error(404)));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let checker = CheckingObserver::new();
+        observable.subscribe(
+            checker
+                .clone()
+                .contramap_err(|error_code: i32| error_code.to_string()),
+        );
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("404".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            Subscription::new_non_disposal_action(observer)
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone().contramap(|value: i32| value * 2));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+    }
+}
@@ -1,13 +1,75 @@
 /// A `Terminated` is a value that an `Observable` can send to an `Observer` to indicate that the observable has terminated.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Terminated<E> {
     Error(E),
     Unsubscribed,
     Completed,
 }
 
+impl<E> Terminated<E> {
+    /// Returns `true` if the terminated is an error.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Terminated::Error(_))
+    }
+
+    /**
+    Converts the terminated into the error, if any.
+
+    # Example
+    ```rust
+    use rx_rust::observer::event::Terminated;
+    assert_eq!(Terminated::Error(123).err(), Some(123));
+    assert_eq!(Terminated::<i32>::Completed.err(), None);
+    ```
+     */
+    pub fn err(self) -> Option<E> {
+        match self {
+            Terminated::Error(error) => Some(error),
+            Terminated::Unsubscribed | Terminated::Completed => None,
+        }
+    }
+
+    /**
+    Maps the error type of the terminated to a new error type using the given function.
+
+    # Example
+    ```rust
+    use rx_rust::observer::event::Terminated;
+    let terminated = Terminated::Error(123);
+    let new_terminated = terminated.map_err(|error_code| error_code.to_string());
+    assert_eq!(new_terminated, Terminated::Error("123".to_owned()));
+    ```
+     */
+    pub fn map_err<E2>(self, f: impl FnOnce(E) -> E2) -> Terminated<E2> {
+        match self {
+            Terminated::Error(error) => Terminated::Error(f(error)),
+            Terminated::Unsubscribed => Terminated::Unsubscribed,
+            Terminated::Completed => Terminated::Completed,
+        }
+    }
+}
+
+/**
+Converts the terminated into a `Result`, so it composes with `?`. Both `Completed` and `Unsubscribed` are `Ok(())`.
+
+# Example
+```rust
+use rx_rust::observer::event::Terminated;
+let result: Result<(), i32> = Terminated::Error(123).into();
+assert_eq!(result, Err(123));
+```
+ */
+impl<E> From<Terminated<E>> for Result<(), E> {
+    fn from(terminated: Terminated<E>) -> Self {
+        match terminated.err() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
 /// An `Event` is a value that an `Observable` can send to an `Observer`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Event<T, E> {
     Next(T),
     Terminated(Terminated<E>),
@@ -47,11 +109,7 @@ impl<T, E> Event<T, E> {
     pub fn map_error<E2>(self, f: impl Fn(E) -> E2) -> Event<T, E2> {
         match self {
             Event::Next(value) => Event::Next(value),
-            Event::Terminated(terminated) => match terminated {
-                Terminated::Error(error) => Event::Terminated(Terminated::Error(f(error))),
-                Terminated::Unsubscribed => Event::Terminated(Terminated::Unsubscribed),
-                Terminated::Completed => Event::Terminated(Terminated::Completed),
-            },
+            Event::Terminated(terminated) => Event::Terminated(terminated.map_err(f)),
         }
     }
 }
@@ -107,4 +165,32 @@ mod tests {
         let new_event = event.map_error(|error_code| error_code.to_string());
         assert_eq!(new_event, Event::Terminated(Terminated::Completed));
     }
+
+    #[test]
+    fn test_terminated_helpers() {
+        assert!(Terminated::Error(123).is_error());
+        assert!(!Terminated::<i32>::Completed.is_error());
+        assert!(!Terminated::<i32>::Unsubscribed.is_error());
+        assert_eq!(Terminated::Error(123).err(), Some(123));
+        assert_eq!(Terminated::<i32>::Unsubscribed.err(), None);
+        assert_eq!(
+            Terminated::Error(123).map_err(|error_code| error_code + 1),
+            Terminated::Error(124)
+        );
+        assert_eq!(
+            Terminated::<i32>::Completed.map_err(|error_code| error_code + 1),
+            Terminated::Completed
+        );
+    }
+
+    #[test]
+    fn test_terminated_into_result() {
+        fn handle(terminated: Terminated<i32>) -> Result<(), String> {
+            Result::from(terminated).map_err(|error_code| error_code.to_string())?;
+            Ok(())
+        }
+        assert_eq!(handle(Terminated::Completed), Ok(()));
+        assert_eq!(handle(Terminated::Unsubscribed), Ok(()));
+        assert_eq!(handle(Terminated::Error(123)), Err("123".to_owned()));
+    }
 }
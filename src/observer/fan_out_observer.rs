@@ -0,0 +1,132 @@
+use super::{event::Event, Observer};
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::RwLock,
+};
+
+/**
+An observer that passes a clone of each event to every child observer, so one subscription can feed several sinks.
+A child that panics is isolated: it is set to terminated and receives no more events, while the other children carry on.
+
+# Example
+```rust
+use rx_rust::observable::Observable;
+use rx_rust::observer::anonymous_observer::AnonymousObserver;
+use rx_rust::observer::event::Event;
+use rx_rust::observer::fan_out_observer::FanOutObserver;
+use rx_rust::observer::Observer;
+use rx_rust::operators::just::Just;
+use std::convert::Infallible;
+let log: Box<dyn Observer<i32, Infallible>> = Box::new(AnonymousObserver::new(
+    |event: Event<i32, Infallible>| println!("log: {:?}", event),
+));
+let metrics: Box<dyn Observer<i32, Infallible>> = Box::new(AnonymousObserver::new(
+    |event: Event<i32, Infallible>| println!("metrics: {:?}", event),
+));
+Just::new(123).subscribe(FanOutObserver::new(vec![log, metrics]));
+```
+*/
+pub struct FanOutObserver<T, E> {
+    observers: Vec<Box<dyn Observer<T, E>>>,
+    terminated: RwLock<bool>,
+}
+
+impl<T, E> FanOutObserver<T, E> {
+    pub fn new(observers: Vec<Box<dyn Observer<T, E>>>) -> FanOutObserver<T, E> {
+        FanOutObserver {
+            observers,
+            terminated: RwLock::new(false),
+        }
+    }
+}
+
+impl<T, E> Observer<T, E> for FanOutObserver<T, E>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    fn on(&self, event: Event<T, E>) {
+        for observer in self.observers.iter() {
+            let event = event.clone();
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                observer.notify_if_unterminated(event);
+            }));
+            if result.is_err() {
+                observer.set_terminated(true);
+            }
+        }
+    }
+
+    fn terminated(&self) -> bool {
+        *self.terminated.read().unwrap()
+    }
+
+    fn set_terminated(&self, terminated: bool) {
+        *self.terminated.write().unwrap() = terminated;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::Observable,
+        observer::{anonymous_observer::AnonymousObserver, event::Terminated},
+        operators::create::Create,
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source() -> impl Observable<i32, String> {
+        Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_fan_out() {
+        let checker1 = CheckingObserver::new();
+        let checker2 = CheckingObserver::new();
+        source().subscribe(FanOutObserver::new(vec![
+            Box::new(checker1.clone()),
+            Box::new(checker2.clone()),
+        ]));
+        assert!(checker1.is_values_matched(&[1, 2]));
+        assert!(checker1.is_completed());
+        assert!(checker2.is_values_matched(&[1, 2]));
+        assert!(checker2.is_completed());
+    }
+
+    #[test]
+    fn test_panicking_child() {
+        let panicking = AnonymousObserver::new(|event: Event<i32, String>| {
+            if event == Event::Next(1) {
+                panic!("sink failed");
+            }
+        });
+        let checker = CheckingObserver::new();
+        let fan_out = FanOutObserver::new(vec![Box::new(panicking), Box::new(checker.clone())]);
+        source().subscribe(fan_out);
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            Subscription::new_non_disposal_action(observer)
+        });
+        let checker1 = CheckingObserver::new();
+        let checker2 = CheckingObserver::new();
+        let subscription = observable.subscribe(FanOutObserver::new(vec![
+            Box::new(checker1.clone()),
+            Box::new(checker2.clone()),
+        ]));
+        subscription.unsubscribe();
+        assert!(checker1.is_unsubscribed());
+        assert!(checker2.is_unsubscribed());
+    }
+}
@@ -1,5 +1,7 @@
 pub mod anonymous_observer;
+pub mod contramap_observer;
 pub mod event;
+pub mod fan_out_observer;
 pub mod observer_ext;
 
 use event::Event;
@@ -0,0 +1,657 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+/**
+The feedback hook of `adaptive_buffer`. It holds the current batch size, which is doubled when the consumer is slow and halved when the consumer is fast, always staying between `min` and `max`.
+The operator reports by itself whether the consumer is still busy when a new batch is ready. Consumers that finish their work asynchronously can report too.
+
+# Example
+```rust
+use rx_rust::operators::adaptive_buffer::AdaptiveBufferFeedback;
+let feedback = AdaptiveBufferFeedback::new(1, 8);
+feedback.report_slow();
+assert_eq!(feedback.batch_size(), 2);
+feedback.report_fast();
+assert_eq!(feedback.batch_size(), 1);
+```
+*/
+#[derive(Clone)]
+pub struct AdaptiveBufferFeedback {
+    min: usize,
+    max: usize,
+    batch_size: Arc<AtomicUsize>,
+}
+
+impl AdaptiveBufferFeedback {
+    /// Create a feedback hook starting at the `min` batch size. Panics if `min` is 0 or greater than `max`.
+    pub fn new(min: usize, max: usize) -> AdaptiveBufferFeedback {
+        assert!(min > 0, "min batch size must be greater than 0");
+        assert!(min <= max, "min batch size must not be greater than max");
+        AdaptiveBufferFeedback {
+            min,
+            max,
+            batch_size: Arc::new(AtomicUsize::new(min)),
+        }
+    }
+
+    /// The current batch size.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size.load(Ordering::SeqCst)
+    }
+
+    /// Report the consumer is slow, the batch size grows.
+    pub fn report_slow(&self) {
+        let max = self.max;
+        _ = self
+            .batch_size
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |size| {
+                Some(size.saturating_mul(2).min(max))
+            });
+    }
+
+    /// Report the consumer is fast, the batch size shrinks.
+    pub fn report_fast(&self) {
+        let min = self.min;
+        _ = self
+            .batch_size
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |size| {
+                Some((size / 2).max(min))
+            });
+    }
+}
+
+/// This is an observable that collects the values into batches sized by the `AdaptiveBufferFeedback`. A partial batch is flushed `max_delay` after its first value, so a slow source doesn't hold it back. The batches and the completed event are delivered one by one on the scheduler. The error and unsubscribed events will post immediately.
+pub struct AdaptiveBuffer<O, S> {
+    source: O,
+    feedback: AdaptiveBufferFeedback,
+    /// Whether `feedback` is shared by all the subscriptions, instead of a template for a new hook per subscription.
+    shared_feedback: bool,
+    max_delay: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<O, S> AdaptiveBuffer<O, S> {
+    /// Create an adaptive buffer with its own feedback hook for each subscription, starting at the `min` batch size. Panics if `min` is 0 or greater than `max`.
+    pub fn new(
+        source: O,
+        min: usize,
+        max: usize,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> AdaptiveBuffer<O, S> {
+        AdaptiveBuffer {
+            source,
+            feedback: AdaptiveBufferFeedback::new(min, max),
+            shared_feedback: false,
+            max_delay,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+
+    /// Create an adaptive buffer driven by the given feedback hook. The hook is shared by all the subscriptions, so the batches of one subscription resize the batches of the others.
+    pub fn with_feedback(
+        source: O,
+        feedback: AdaptiveBufferFeedback,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> AdaptiveBuffer<O, S> {
+        AdaptiveBuffer {
+            source,
+            feedback,
+            shared_feedback: true,
+            max_delay,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<O, S> Clone for AdaptiveBuffer<O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        AdaptiveBuffer {
+            source: self.source.clone(),
+            feedback: self.feedback.clone(),
+            shared_feedback: self.shared_feedback,
+            max_delay: self.max_delay,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct AdaptiveBufferState<T, E> {
+    buffer: Vec<T>,
+    /// Incremented on each flush, so the timer of a flushed buffer does nothing.
+    batch_id: u64,
+    timer: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+    queue: VecDeque<Event<Vec<T>, E>>,
+    delivering: bool,
+    /// Incremented on each delivery, so the disposal of a finished delivery doesn't replace the disposal of a newer one.
+    delivery_id: u64,
+    disposal: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+}
+
+impl<T, E> AdaptiveBufferState<T, E> {
+    /// Queue the buffer as a batch, and take its timer so it can be dropped outside the lock.
+    fn flush(&mut self) -> Option<Disposal<Box<dyn FnOnce() + Send>>> {
+        let batch = std::mem::take(&mut self.buffer);
+        self.queue.push_back(Event::Next(batch));
+        self.batch_id += 1;
+        self.timer.take()
+    }
+}
+
+struct Batching<T, E, S, OR> {
+    feedback: AdaptiveBufferFeedback,
+    max_delay: Duration,
+    scheduler: Arc<S>,
+    observer: OR,
+    state: Mutex<AdaptiveBufferState<T, E>>,
+}
+
+impl<T, E, S, OR> Batching<T, E, S, OR>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    S: Scheduler,
+    OR: Observer<Vec<T>, E>,
+{
+    fn on_event(self: &Arc<Self>, event: Event<T, E>) {
+        let mut state = self.state.lock().unwrap();
+        match event {
+            Event::Next(value) => {
+                state.buffer.push(value);
+                if state.buffer.len() < self.feedback.batch_size() {
+                    let batch_id = (state.buffer.len() == 1).then_some(state.batch_id);
+                    drop(state);
+                    if let Some(batch_id) = batch_id {
+                        self.start_timer(batch_id);
+                    }
+                    return;
+                }
+                if state.delivering {
+                    self.feedback.report_slow();
+                } else {
+                    self.feedback.report_fast();
+                }
+                let timer = state.flush();
+                drop(state);
+                drop(timer);
+            }
+            Event::Terminated(Terminated::Completed) => {
+                let timer = if state.buffer.is_empty() {
+                    state.timer.take()
+                } else {
+                    state.flush()
+                };
+                state
+                    .queue
+                    .push_back(Event::Terminated(Terminated::Completed));
+                drop(state);
+                drop(timer);
+            }
+            Event::Terminated(terminated) => {
+                state.queue.clear();
+                let timer = state.timer.take();
+                drop(state);
+                drop(timer);
+                self.observer
+                    .notify_if_unterminated(Event::Terminated(terminated));
+                return;
+            }
+        }
+        self.deliver();
+    }
+
+    /// Flush the buffer `max_delay` after its first value, unless it has been flushed by then.
+    fn start_timer(self: &Arc<Self>, batch_id: u64) {
+        let batching = self.clone();
+        let timer = self.scheduler.schedule(
+            move || {
+                let mut state = batching.state.lock().unwrap();
+                if state.batch_id != batch_id || state.buffer.is_empty() {
+                    return;
+                }
+                let timer = state.flush();
+                drop(state);
+                drop(timer);
+                batching.deliver();
+            },
+            Some(self.max_delay),
+        );
+        let timer = timer.to_boxed();
+        let mut state = self.state.lock().unwrap();
+        let timer = if state.batch_id == batch_id {
+            state.timer.replace(timer)
+        } else {
+            // Flushed while the timer was being scheduled.
+            Some(timer)
+        };
+        drop(state);
+        drop(timer);
+    }
+
+    /// Deliver the queued events one by one on the scheduler, unless they are being delivered.
+    fn deliver(self: &Arc<Self>) {
+        let mut state = self.state.lock().unwrap();
+        if state.delivering || state.queue.is_empty() {
+            return;
+        }
+        state.delivering = true;
+        state.delivery_id += 1;
+        let delivery_id = state.delivery_id;
+        drop(state);
+        let batching = self.clone();
+        let disposal = self.scheduler.schedule(
+            move || loop {
+                let mut state = batching.state.lock().unwrap();
+                let event = match state.queue.pop_front() {
+                    Some(event) => event,
+                    None => {
+                        state.delivering = false;
+                        break;
+                    }
+                };
+                drop(state);
+                batching.observer.notify_if_unterminated(event);
+            },
+            None,
+        );
+        let disposal = disposal.to_boxed();
+        let mut state = self.state.lock().unwrap();
+        let disposal = if state.delivery_id == delivery_id {
+            state.disposal.replace(disposal)
+        } else {
+            // Delivered while the delivery was being scheduled, and a newer delivery has started.
+            Some(disposal)
+        };
+        drop(state);
+        drop(disposal);
+    }
+
+    fn stop(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.queue.clear();
+        let disposal = state.disposal.take();
+        let timer = state.timer.take();
+        drop(state);
+        drop(disposal);
+        drop(timer);
+    }
+}
+
+impl<T, E, O, S> Observable<Vec<T>, E> for AdaptiveBuffer<O, S>
+where
+    O: Observable<T, E>,
+    S: Scheduler,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let feedback = if self.shared_feedback {
+            self.feedback
+        } else {
+            AdaptiveBufferFeedback::new(self.feedback.min, self.feedback.max)
+        };
+        let batching = Arc::new(Batching {
+            feedback,
+            max_delay: self.max_delay,
+            scheduler: self.scheduler,
+            observer,
+            state: Mutex::new(AdaptiveBufferState {
+                buffer: Vec::new(),
+                batch_id: 0,
+                timer: None,
+                queue: VecDeque::new(),
+                delivering: false,
+                delivery_id: 0,
+                disposal: None,
+            }),
+        });
+        let batching_cloned = batching.clone();
+        let source_observer =
+            AnonymousObserver::new(move |event: Event<T, E>| batching_cloned.on_event(event));
+        let subscription = self.source.subscribe(source_observer);
+        subscription.insert_disposal_action(move || batching.stop())
+    }
+}
+
+/// Make the `Observable` adaptively bufferable.
+pub trait AdaptiveBufferableObservable<T, E> {
+    /**
+    Collects the values into batches between `min` and `max` in size. The batch grows when the consumer is still busy with the previous batch, and shrinks when the consumer is idle. A partial batch is flushed `max_delay` after its first value, which bounds the latency on a slow source.
+    Each subscription adapts its batch size by itself.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::adaptive_buffer::AdaptiveBufferableObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new(333);
+        let observable = observable.adaptive_buffer(1, 100, Duration::from_millis(50), TokioScheduler::new());
+        observable.subscribe_on_event(|event| {
+            println!("{:?}", event);
+        });
+    }
+    ```
+     */
+    fn adaptive_buffer<S>(
+        self,
+        min: usize,
+        max: usize,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static;
+
+    /**
+    Same as `adaptive_buffer`, but the batch size is driven by the given feedback hook, so the consumer can report its processing latency.
+    The hook is shared by all the subscriptions, so the batches of one subscription resize the batches of the others.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::adaptive_buffer::{AdaptiveBufferFeedback, AdaptiveBufferableObservable};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let feedback = AdaptiveBufferFeedback::new(1, 100);
+        let observable = Just::new(333);
+        let observable = observable.adaptive_buffer_with_feedback(
+            feedback.clone(),
+            Duration::from_millis(50),
+            TokioScheduler::new(),
+        );
+        observable.subscribe_on_next(move |batch| {
+            println!("{:?}", batch);
+            feedback.report_slow();
+        });
+    }
+    ```
+     */
+    fn adaptive_buffer_with_feedback<S>(
+        self,
+        feedback: AdaptiveBufferFeedback,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static;
+}
+
+impl<O, T, E> AdaptiveBufferableObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn adaptive_buffer<S>(
+        self,
+        min: usize,
+        max: usize,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        AdaptiveBuffer::new(self, min, max, max_delay, scheduler)
+    }
+
+    fn adaptive_buffer_with_feedback<S>(
+        self,
+        feedback: AdaptiveBufferFeedback,
+        max_delay: Duration,
+        scheduler: S,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        AdaptiveBuffer::with_feedback(self, feedback, max_delay, scheduler)
+    }
+}
+
+#[cfg(feature = "tokio-scheduler")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable,
+        operators::create::Create,
+        scheduler::{queue_scheduler::QueueScheduler, tokio_scheduler::TokioScheduler},
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::time::Duration;
+    use tokio::time::sleep;
+
+    #[test]
+    fn test_feedback_bounds() {
+        let feedback = AdaptiveBufferFeedback::new(2, 5);
+        assert_eq!(feedback.batch_size(), 2);
+        feedback.report_slow();
+        assert_eq!(feedback.batch_size(), 4);
+        feedback.report_slow();
+        assert_eq!(feedback.batch_size(), 5);
+        feedback.report_fast();
+        assert_eq!(feedback.batch_size(), 2);
+        feedback.report_fast();
+        assert_eq!(feedback.batch_size(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_grows_when_consumer_busy() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            for value in 1..=5 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.adaptive_buffer(2, 8, Duration::from_secs(1), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4], vec![5]]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Next(3));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.adaptive_buffer(2, 8, Duration::from_secs(1), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+        sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[]));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribed() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.adaptive_buffer(2, 8, Duration::from_secs(1), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unsubscribed());
+    }
+
+    #[tokio::test]
+    async fn test_external_feedback() {
+        let feedback = AdaptiveBufferFeedback::new(1, 4);
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        });
+        feedback.report_slow();
+        let observable = observable.adaptive_buffer_with_feedback(
+            feedback,
+            Duration::from_secs(1),
+            TokioScheduler::new(),
+        );
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_max_delay() {
+        let scheduler = QueueScheduler::new();
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let observable =
+            observable.adaptive_buffer(4, 8, Duration::from_millis(50), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        emitter.emit(2);
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(50));
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+
+        // A full batch cancels its timer.
+        for value in 3..=6 {
+            emitter.emit(value);
+        }
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(50));
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4, 5, 6]]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_feedback_per_subscription() {
+        let scheduler = QueueScheduler::new();
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let observable =
+            observable.adaptive_buffer(1, 8, Duration::from_millis(50), scheduler.clone());
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        // The batches are ready while the first one is still waiting to be delivered, so the batch size grows.
+        for value in 1..=4 {
+            emitter.emit(value);
+        }
+        let checker2 = CheckingObserver::new();
+        let subscription2 = observable.subscribe(checker2.clone());
+        emitter.emit(5);
+        scheduler.run_until_idle();
+        assert!(checker1.is_values_matched(&[vec![1], vec![2], vec![3, 4], vec![5]]));
+        assert!(checker2.is_values_matched(&[vec![5]]));
+        assert_eq!(scheduler.now(), Duration::from_millis(50));
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    /// A `QueueScheduler` that runs a task on another thread right after scheduling the next one, before returning its disposal.
+    struct RacingScheduler {
+        inner: QueueScheduler,
+        race: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+    }
+
+    impl Scheduler for RacingScheduler {
+        fn schedule(
+            &self,
+            task: impl FnOnce() + Send + 'static,
+            delay: Option<Duration>,
+        ) -> Disposal<impl FnOnce() + Send + 'static> {
+            let disposal = self.inner.schedule(task, delay);
+            let race = self.race.lock().unwrap().take();
+            if let Some(race) = race {
+                std::thread::spawn(race).join().unwrap();
+            }
+            disposal
+        }
+    }
+
+    #[test]
+    fn test_delivery_racing_on_another_thread() {
+        let inner = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let batching = Arc::new(Batching {
+            feedback: AdaptiveBufferFeedback::new(1, 1),
+            max_delay: Duration::from_secs(1),
+            scheduler: Arc::new(RacingScheduler {
+                inner: inner.clone(),
+                race: Mutex::new(None),
+            }),
+            observer: checker.clone(),
+            state: Mutex::new(AdaptiveBufferState {
+                buffer: Vec::new(),
+                batch_id: 0,
+                timer: None,
+                queue: VecDeque::new(),
+                delivering: false,
+                delivery_id: 0,
+                disposal: None,
+            }),
+        });
+        // While the first delivery is being scheduled, another thread runs it and starts the second delivery.
+        let batching_cloned = batching.clone();
+        let inner_cloned = inner.clone();
+        *batching.scheduler.race.lock().unwrap() = Some(Box::new(move || {
+            inner_cloned.run_one();
+            batching_cloned.on_event(Event::Next(2));
+        }));
+        batching.on_event(Event::<i32, String>::Next(1));
+        inner.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1], vec![2]]));
+        batching.on_event(Event::Next(3));
+        inner.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1], vec![2], vec![3]]));
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{collections::VecDeque, sync::Mutex};
+
+/// This is an observable that collects the values of the source observable into `Vec` chunks of `count` values, and emits each full chunk. The partial chunk is emitted before the completed event.
+#[derive(Clone)]
+pub struct BufferCount<O> {
+    source: O,
+    count: usize,
+}
+
+impl<O> BufferCount<O> {
+    /// Panics if `count` is 0.
+    pub fn new(source: O, count: usize) -> BufferCount<O> {
+        assert!(count > 0, "the count of buffer_count must be positive");
+        BufferCount { source, count }
+    }
+}
+
+impl<T, E, O> Observable<Vec<T>, E> for BufferCount<O>
+where
+    O: Observable<T, E>,
+    T: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let count = self.count;
+        let buffer = Mutex::new(Vec::with_capacity(count));
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut buffer = buffer.lock().unwrap();
+                buffer.push(value);
+                if buffer.len() < count {
+                    return;
+                }
+                let chunk = std::mem::replace(&mut *buffer, Vec::with_capacity(count));
+                drop(buffer);
+                observer.notify_if_unterminated(Event::Next(chunk));
+            }
+            Event::Terminated(Terminated::Completed) => {
+                let rest = std::mem::take(&mut *buffer.lock().unwrap());
+                if !rest.is_empty() {
+                    observer.notify_if_unterminated(Event::Next(rest));
+                }
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// This is an observable that opens a new `Vec` buffer every `skip` values of the source observable, and emits each buffer once it holds `count` values. The buffers overlap when `skip` is less than `count`, and values are dropped between buffers when `skip` is greater than `count`. The partial buffers are emitted before the completed event.
+#[derive(Clone)]
+pub struct BufferCountWithSkip<O> {
+    source: O,
+    count: usize,
+    skip: usize,
+}
+
+impl<O> BufferCountWithSkip<O> {
+    /// Panics if `count` or `skip` is 0.
+    pub fn new(source: O, count: usize, skip: usize) -> BufferCountWithSkip<O> {
+        assert!(
+            count > 0,
+            "the count of buffer_count_with_skip must be positive"
+        );
+        assert!(
+            skip > 0,
+            "the skip of buffer_count_with_skip must be positive"
+        );
+        BufferCountWithSkip {
+            source,
+            count,
+            skip,
+        }
+    }
+}
+
+struct SlidingBuffers<T> {
+    index: usize,
+    buffers: VecDeque<Vec<T>>,
+}
+
+impl<T, E, O> Observable<Vec<T>, E> for BufferCountWithSkip<O>
+where
+    O: Observable<T, E>,
+    T: Clone + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let count = self.count;
+        let skip = self.skip;
+        let state = Mutex::new(SlidingBuffers {
+            index: 0,
+            buffers: VecDeque::new(),
+        });
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = state.lock().unwrap();
+                if state.index % skip == 0 {
+                    state.buffers.push_back(Vec::with_capacity(count));
+                }
+                state.index += 1;
+                for buffer in state.buffers.iter_mut() {
+                    buffer.push(value.clone());
+                }
+                // The oldest buffer is the only one that can be full.
+                let full = state
+                    .buffers
+                    .front()
+                    .is_some_and(|buffer| buffer.len() == count);
+                let chunk = if full {
+                    state.buffers.pop_front()
+                } else {
+                    None
+                };
+                drop(state);
+                if let Some(chunk) = chunk {
+                    observer.notify_if_unterminated(Event::Next(chunk));
+                }
+            }
+            Event::Terminated(Terminated::Completed) => {
+                let rest = std::mem::take(&mut state.lock().unwrap().buffers);
+                for buffer in rest {
+                    if !buffer.is_empty() {
+                        observer.notify_if_unterminated(Event::Next(buffer));
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` bufferable by count.
+pub trait BufferCountObservable<T, E> {
+    /**
+    Collects the values into `Vec` chunks of `count` values, and emits each full chunk. The partial chunk is emitted before the completed event, and dropped on error.
+
+    Panics if `count` is 0.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::buffer_count::BufferCountObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=5);
+    let observable = observable.buffer_count(2);
+    observable.subscribe_on_next(|chunk| {
+        println!("{:?}", chunk);
+    });
+    ```
+     */
+    fn buffer_count(self, count: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Send + 'static;
+
+    /**
+    Opens a new buffer every `skip` values, and emits each buffer once it holds `count` values. The buffers overlap when `skip` is less than `count` (e.g. `buffer_count_with_skip(3, 1)` for moving windows), and values are dropped between buffers when `skip` is greater than `count`. The partial buffers are emitted before the completed event, and dropped on error.
+
+    Panics if `count` or `skip` is 0.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::buffer_count::BufferCountObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=5);
+    let observable = observable.buffer_count_with_skip(3, 1);
+    observable.subscribe_on_next(|chunk| {
+        println!("{:?}", chunk);
+    });
+    ```
+     */
+    fn buffer_count_with_skip(self, count: usize, skip: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Clone + Send + 'static;
+}
+
+impl<O, T, E> BufferCountObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn buffer_count(self, count: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Send + 'static,
+    {
+        BufferCount::new(self, count)
+    }
+
+    fn buffer_count_with_skip(self, count: usize, skip: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Clone + Send + 'static,
+    {
+        BufferCountWithSkip::new(self, count, skip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+
+    fn source(values: Vec<i32>, completed: bool) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            if completed {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = source(vec![1, 2, 3, 4, 5], true).buffer_count(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4], vec![5]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_exact_chunks() {
+        let observable = source(vec![1, 2, 3, 4], true).buffer_count(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = source(vec![1, 2, 3], false).buffer_count(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the count of buffer_count must be positive")]
+    fn test_zero_count() {
+        source(vec![], true).buffer_count(0);
+    }
+
+    #[test]
+    fn test_sliding() {
+        let observable = source(vec![1, 2, 3, 4, 5], true).buffer_count_with_skip(3, 1);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            vec![1, 2, 3],
+            vec![2, 3, 4],
+            vec![3, 4, 5],
+            vec![4, 5],
+            vec![5],
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_gapped() {
+        let observable = source(vec![1, 2, 3, 4, 5, 6, 7], true).buffer_count_with_skip(2, 3);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![4, 5], vec![7]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_skip_equals_count() {
+        let observable = source(vec![1, 2, 3, 4, 5], true).buffer_count_with_skip(2, 2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4], vec![5]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_sliding_error() {
+        let observable = source(vec![1, 2, 3, 4], false).buffer_count_with_skip(3, 2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2, 3]]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the skip of buffer_count_with_skip must be positive")]
+    fn test_zero_skip() {
+        source(vec![], true).buffer_count_with_skip(2, 0);
+    }
+}
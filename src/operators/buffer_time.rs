@@ -0,0 +1,227 @@
+use super::interval::Interval;
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// This is an observable that collects the values of the source observable received in each time window of `duration`, and emits them as a `Vec` at the end of the window, even if it is empty. The values of the unfinished window are emitted before the completed event.
+pub struct BufferTime<O, S> {
+    source: O,
+    duration: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<O, S> BufferTime<O, S> {
+    pub fn new(source: O, duration: Duration, scheduler: S) -> BufferTime<O, S> {
+        BufferTime {
+            source,
+            duration,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<O, S> Clone for BufferTime<O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        BufferTime {
+            source: self.source.clone(),
+            duration: self.duration,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct BufferTimeState<T> {
+    stopped: bool,
+    buffer: Vec<T>,
+    timer_subscription: Option<Subscription>,
+}
+
+impl<T, E, O, S> Observable<Vec<T>, E> for BufferTime<O, S>
+where
+    O: Observable<T, E>,
+    S: Scheduler,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(BufferTimeState {
+            stopped: false,
+            buffer: Vec::new(),
+            timer_subscription: None,
+        }));
+
+        let state_cloned = state.clone();
+        let observer_cloned = observer.clone();
+        let timer_observer = AnonymousObserver::new(move |event: Event<usize, Infallible>| {
+            if let Event::Next(_) = event {
+                let mut state = state_cloned.lock().unwrap();
+                if state.stopped {
+                    return;
+                }
+                let buffer = std::mem::take(&mut state.buffer);
+                drop(state);
+                observer_cloned.notify_if_unterminated(Event::Next(buffer));
+            }
+        });
+        let timer_subscription =
+            Interval::new(self.duration, self.scheduler.clone()).subscribe(timer_observer);
+        state.lock().unwrap().timer_subscription = Some(timer_subscription);
+
+        let state_cloned = state.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = state_cloned.lock().unwrap();
+                if !state.stopped {
+                    state.buffer.push(value);
+                }
+            }
+            Event::Terminated(terminated) => {
+                let mut state = state_cloned.lock().unwrap();
+                state.stopped = true;
+                let buffer = std::mem::take(&mut state.buffer);
+                let timer_subscription = state.timer_subscription.take();
+                drop(state);
+                drop(timer_subscription);
+                if let Terminated::Completed = terminated {
+                    if !buffer.is_empty() {
+                        observer.notify_if_unterminated(Event::Next(buffer));
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+        });
+        let subscription = self.source.subscribe(source_observer);
+        subscription.insert_disposal_action(move || {
+            let mut state = state.lock().unwrap();
+            state.stopped = true;
+            let timer_subscription = state.timer_subscription.take();
+            drop(state);
+            drop(timer_subscription);
+        })
+    }
+}
+
+/// Make the `Observable` bufferable by time.
+pub trait BufferTimeObservable<T, E> {
+    /**
+    Collects the values received in each time window of `duration`, and emits them as a `Vec` at the end of the window, even if it is empty. The values of the unfinished window are emitted before the completed event, and dropped on error. The timer is cancelled when the subscription is unsubscribed or dropped.
+
+    # Example
+    ```rust
+    use rx_rust::operators::interval::Interval;
+    use rx_rust::operators::buffer_time::BufferTimeObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+    use std::time::Duration;
+    let scheduler = QueueScheduler::new();
+    let observable = Interval::new(Duration::from_millis(10), scheduler.clone());
+    let observable = observable.buffer_time(Duration::from_millis(35), scheduler.clone());
+    let subscription = observable.subscribe_on_next(|buffer| {
+        println!("{:?}", buffer);
+    });
+    scheduler.run_one();
+    ```
+     */
+    fn buffer_time<S>(self, duration: Duration, scheduler: S) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static;
+}
+
+impl<O, T, E> BufferTimeObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn buffer_time<S>(self, duration: Duration, scheduler: S) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        BufferTime::new(self, duration, scheduler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+
+    #[test]
+    fn test_completed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (5, Event::Next(1)),
+                (8, Event::Next(2)),
+                (15, Event::Next(3)),
+                (35, Event::Next(4)),
+                (38, Event::Terminated(Terminated::Completed)),
+            ],
+        )
+        .buffer_time(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3], vec![], vec![4]]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(38));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (5, Event::Next(1)),
+                (15, Event::Next(2)),
+                (18, Event::Terminated(Terminated::Error("error".to_owned()))),
+            ],
+        )
+        .buffer_time(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1]]));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(scheduler.clone(), vec![(5, Event::Next(1))])
+            .buffer_time(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        scheduler.run_one();
+        assert!(checker.is_values_matched(&[vec![1]]));
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+}
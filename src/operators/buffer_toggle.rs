@@ -0,0 +1,370 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that opens a buffer each time `openings` emits, collects the values of the source observable into every open buffer, and emits a buffer as a `Vec` when the closing observable returned by `closing_selector` for it emits or completes. The open buffers are emitted before the completed event.
+pub struct BufferToggle<V, CV, O, OP, F> {
+    source: O,
+    openings: OP,
+    closing_selector: Arc<F>,
+    _marker: PhantomData<fn(V) -> CV>,
+}
+
+impl<V, CV, O, OP, F> BufferToggle<V, CV, O, OP, F> {
+    pub fn new(source: O, openings: OP, closing_selector: F) -> BufferToggle<V, CV, O, OP, F> {
+        BufferToggle {
+            source,
+            openings,
+            closing_selector: Arc::new(closing_selector),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<V, CV, O, OP, F> Clone for BufferToggle<V, CV, O, OP, F>
+where
+    O: Clone,
+    OP: Clone,
+{
+    fn clone(&self) -> Self {
+        BufferToggle {
+            source: self.source.clone(),
+            openings: self.openings.clone(),
+            closing_selector: self.closing_selector.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct OpenBuffer<T> {
+    values: Vec<T>,
+    /// `None` while the closing observable is being subscribed.
+    closing_subscription: Option<Subscription>,
+}
+
+struct BufferToggleState<T> {
+    stopped: bool,
+    next_id: u64,
+    buffers: BTreeMap<u64, OpenBuffer<T>>,
+    openings_subscription: Option<Subscription>,
+    source_subscription: Option<Subscription>,
+}
+
+impl<T> BufferToggleState<T> {
+    /// Stop and take the open buffers and every subscription, so they can be dropped outside the lock.
+    fn stop(&mut self) -> (Vec<Vec<T>>, Vec<Subscription>) {
+        self.stopped = true;
+        let mut buffers = Vec::new();
+        let mut subscriptions = Vec::new();
+        for buffer in std::mem::take(&mut self.buffers).into_values() {
+            buffers.push(buffer.values);
+            subscriptions.extend(buffer.closing_subscription);
+        }
+        subscriptions.extend(self.openings_subscription.take());
+        subscriptions.extend(self.source_subscription.take());
+        (buffers, subscriptions)
+    }
+}
+
+struct Toggling<T, E, F, OR> {
+    closing_selector: Arc<F>,
+    observer: Arc<OR>,
+    state: Mutex<BufferToggleState<T>>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<T, E, F, OR> Toggling<T, E, F, OR>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    OR: Observer<Vec<T>, E>,
+{
+    fn open<V, C, CV>(self: &Arc<Self>, value: V)
+    where
+        F: Fn(V) -> C + Sync + Send + 'static,
+        C: Observable<CV, E>,
+    {
+        let closing = (self.closing_selector)(value);
+        let id = {
+            let mut state = self.state.lock().unwrap();
+            if state.stopped {
+                return;
+            }
+            let id = state.next_id;
+            state.next_id += 1;
+            state.buffers.insert(
+                id,
+                OpenBuffer {
+                    values: Vec::new(),
+                    closing_subscription: None,
+                },
+            );
+            id
+        };
+        let toggling = self.clone();
+        let closing_observer = AnonymousObserver::new(move |event: Event<CV, E>| match event {
+            Event::Next(_) | Event::Terminated(Terminated::Completed) => toggling.close(id),
+            Event::Terminated(Terminated::Error(error)) => {
+                toggling.finish(Terminated::Error(error))
+            }
+            Event::Terminated(Terminated::Unsubscribed) => {}
+        });
+        let subscription = closing.subscribe(closing_observer);
+        let mut state = self.state.lock().unwrap();
+        match state.buffers.get_mut(&id) {
+            Some(buffer) => buffer.closing_subscription = Some(subscription),
+            None => {
+                // The buffer has already been closed, or everything has stopped.
+                drop(state);
+                drop(subscription);
+            }
+        }
+    }
+
+    fn close(&self, id: u64) {
+        let buffer = self.state.lock().unwrap().buffers.remove(&id);
+        if let Some(buffer) = buffer {
+            self.observer
+                .notify_if_unterminated(Event::Next(buffer.values));
+            drop(buffer.closing_subscription);
+        }
+    }
+
+    fn finish(&self, terminated: Terminated<E>) {
+        let (buffers, subscriptions) = self.state.lock().unwrap().stop();
+        if let Terminated::Completed = terminated {
+            for buffer in buffers {
+                self.observer.notify_if_unterminated(Event::Next(buffer));
+            }
+        }
+        self.observer
+            .notify_if_unterminated(Event::Terminated(terminated));
+        drop(subscriptions);
+    }
+}
+
+impl<T, E, O, OP, V, F, C, CV> Observable<Vec<T>, E> for BufferToggle<V, CV, O, OP, F>
+where
+    T: Clone + Send + 'static,
+    E: Send + 'static,
+    V: 'static,
+    CV: 'static,
+    O: Observable<T, E>,
+    OP: Observable<V, E>,
+    F: Fn(V) -> C + Sync + Send + 'static,
+    C: Observable<CV, E>,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let toggling = Arc::new(Toggling {
+            closing_selector: self.closing_selector,
+            observer: observer.clone(),
+            state: Mutex::new(BufferToggleState {
+                stopped: false,
+                next_id: 0,
+                buffers: BTreeMap::new(),
+                openings_subscription: None,
+                source_subscription: None,
+            }),
+            _marker: PhantomData,
+        });
+
+        let toggling_cloned = toggling.clone();
+        let openings_observer = AnonymousObserver::new(move |event: Event<V, E>| match event {
+            Event::Next(value) => toggling_cloned.open(value),
+            Event::Terminated(Terminated::Error(error)) => {
+                toggling_cloned.finish(Terminated::Error(error))
+            }
+            Event::Terminated(_) => {}
+        });
+        let openings_subscription = self.openings.subscribe(openings_observer);
+        {
+            let mut state = toggling.state.lock().unwrap();
+            if state.stopped {
+                drop(state);
+                drop(openings_subscription);
+            } else {
+                state.openings_subscription = Some(openings_subscription);
+            }
+        }
+
+        let toggling_cloned = toggling.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = toggling_cloned.state.lock().unwrap();
+                for buffer in state.buffers.values_mut() {
+                    buffer.values.push(value.clone());
+                }
+            }
+            Event::Terminated(terminated) => toggling_cloned.finish(terminated),
+        });
+        let subscription = self.source.subscribe(source_observer);
+        {
+            let mut state = toggling.state.lock().unwrap();
+            if state.stopped {
+                drop(state);
+                drop(subscription);
+            } else {
+                state.source_subscription = Some(subscription);
+            }
+        }
+        Subscription::new(observer, move || {
+            let (buffers, subscriptions) = toggling.state.lock().unwrap().stop();
+            drop(buffers);
+            drop(subscriptions);
+        })
+    }
+}
+
+/// Make the `Observable` bufferable by opening and closing signals.
+pub trait BufferToggleObservable<T, E> {
+    /**
+    Opens a buffer each time `openings` emits, collects the values into every open buffer, and emits a buffer as a `Vec` when the closing observable returned by `closing_selector` for it emits or completes. The buffers may overlap, or leave gaps between them.
+    The open buffers are emitted before the completed event. An error from the source, the openings, or a closing observable is forwarded, dropping the open buffers.
+
+    # Example
+    ```rust
+    use rx_rust::operators::interval::Interval;
+    use rx_rust::operators::timer::Timer;
+    use rx_rust::operators::buffer_toggle::BufferToggleObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+    use std::time::Duration;
+    let scheduler = QueueScheduler::new();
+    let observable = Interval::new(Duration::from_millis(10), scheduler.clone());
+    let openings = Interval::new(Duration::from_millis(100), scheduler.clone());
+    let scheduler_cloned = scheduler.clone();
+    let observable = observable.buffer_toggle(openings, move |_| {
+        Timer::new(Duration::from_millis(50), scheduler_cloned.clone())
+    });
+    let subscription = observable.subscribe_on_next(|buffer| {
+        println!("{:?}", buffer);
+    });
+    ```
+     */
+    fn buffer_toggle<V, C, CV>(
+        self,
+        openings: impl Observable<V, E>,
+        closing_selector: impl Fn(V) -> C + Sync + Send + 'static,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        T: Clone + Send + 'static,
+        E: Send + 'static,
+        V: 'static,
+        CV: 'static,
+        C: Observable<CV, E>;
+}
+
+impl<O, T, E> BufferToggleObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn buffer_toggle<V, C, CV>(
+        self,
+        openings: impl Observable<V, E>,
+        closing_selector: impl Fn(V) -> C + Sync + Send + 'static,
+    ) -> impl Observable<Vec<T>, E>
+    where
+        T: Clone + Send + 'static,
+        E: Send + 'static,
+        V: 'static,
+        CV: 'static,
+        C: Observable<CV, E>,
+    {
+        BufferToggle::new(self, openings, closing_selector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::never::Never,
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+    use std::time::Duration;
+
+    fn values(scheduler: &QueueScheduler, end: Event<i32, String>) -> impl Observable<i32, String> {
+        let mut events: Vec<_> = (1..=9)
+            .map(|value| (value as u64 * 10, Event::Next(value)))
+            .collect();
+        events.push((95, end));
+        timed_source(scheduler.clone(), events)
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let scheduler = QueueScheduler::new();
+        let openings = timed_source(
+            scheduler.clone(),
+            vec![
+                (15, Event::Next(30)),
+                (25, Event::Next(10)),
+                (65, Event::Next(100)),
+            ],
+        );
+        let scheduler_cloned = scheduler.clone();
+        let observable = values(&scheduler, Event::Terminated(Terminated::Completed))
+            .buffer_toggle(openings, move |millis: u64| {
+                timed_source::<()>(
+                    scheduler_cloned.clone(),
+                    vec![(millis, Event::Terminated(Terminated::Completed))],
+                )
+            });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![3], vec![2, 3, 4], vec![7, 8, 9]]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_closing_error() {
+        let scheduler = QueueScheduler::new();
+        let openings = timed_source(scheduler.clone(), vec![(15, Event::Next(()))]);
+        let scheduler_cloned = scheduler.clone();
+        let observable = values(&scheduler, Event::Terminated(Terminated::Completed))
+            .buffer_toggle(openings, move |_| {
+                timed_source::<()>(
+                    scheduler_cloned.clone(),
+                    vec![(20, Event::Terminated(Terminated::Error("error".to_owned())))],
+                )
+            });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+        assert_eq!(scheduler.now(), Duration::from_millis(35));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let openings = timed_source(scheduler.clone(), vec![(15, Event::Next(()))]);
+        let observable = values(&scheduler, Event::Terminated(Terminated::Completed))
+            .buffer_toggle(openings, |_| Never::<(), String>::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        scheduler.run_one();
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+}
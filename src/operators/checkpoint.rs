@@ -0,0 +1,310 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// A `CheckpointStore` persists the offset of the latest processed value, e.g. in a file or a database.
+/// The store must be Sync and Send because it will be used in multiple threads.
+/// The store must be 'static because it will be stored in the observable.
+pub trait CheckpointStore: Sync + Send + 'static {
+    /// Get the saved offset, or `None` if nothing has been saved.
+    fn load(&self) -> Option<u64>;
+
+    /// Save the offset of the latest processed value.
+    fn save(&self, offset: u64);
+}
+
+impl<S> CheckpointStore for Arc<S>
+where
+    S: CheckpointStore,
+{
+    fn load(&self) -> Option<u64> {
+        self.as_ref().load()
+    }
+
+    fn save(&self, offset: u64) {
+        self.as_ref().save(offset)
+    }
+}
+
+/**
+An in-memory `CheckpointStore`, for tests and for pipelines that only resume within the process.
+
+# Example
+```rust
+use rx_rust::operators::checkpoint::{CheckpointStore, MemoryCheckpointStore};
+let store = MemoryCheckpointStore::new();
+assert_eq!(store.load(), None);
+store.save(42);
+assert_eq!(store.load(), Some(42));
+```
+*/
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    offset: Mutex<Option<u64>>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> MemoryCheckpointStore {
+        MemoryCheckpointStore::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self) -> Option<u64> {
+        *self.offset.lock().unwrap()
+    }
+
+    fn save(&self, offset: u64) {
+        *self.offset.lock().unwrap() = Some(offset);
+    }
+}
+
+/// This is an observable that forwards the source observable, and saves the offset of each value to the store once the observer has processed it. Nothing is saved after the observer has terminated or unsubscribed.
+pub struct Checkpoint<T, O, S, F> {
+    source: O,
+    store: Arc<S>,
+    offset: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, S, F> Checkpoint<T, O, S, F> {
+    pub fn new(source: O, store: S, offset: F) -> Checkpoint<T, O, S, F> {
+        Checkpoint {
+            source,
+            store: Arc::new(store),
+            offset: Arc::new(offset),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, S, F> Clone for Checkpoint<T, O, S, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        Checkpoint {
+            source: self.source.clone(),
+            store: self.store.clone(),
+            offset: self.offset.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, O, S, F> Observable<T, E> for Checkpoint<T, O, S, F>
+where
+    T: Sync + Send + 'static,
+    O: Observable<T, E>,
+    S: CheckpointStore,
+    F: Fn(&T) -> u64 + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let store = self.store.clone();
+        let offset = self.offset.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let offset = offset(&value);
+                observer.notify_if_unterminated(Event::Next(value));
+                // Not processed if the observer has gone, so it's delivered again on resume.
+                if !observer.terminated() {
+                    store.save(offset);
+                }
+            }
+            Event::Terminated(_) => observer.notify_if_unterminated(event),
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/**
+This is an observable that loads the saved offset from the store on each subscription, and subscribes to the observable created from it. The offset is `None` when nothing has been saved yet.
+
+# Example
+```rust
+use rx_rust::operators::checkpoint::{CheckpointObservable, MemoryCheckpointStore, ResumeFrom};
+use rx_rust::operators::just::Just;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use std::sync::Arc;
+let store = Arc::new(MemoryCheckpointStore::new());
+let observable = ResumeFrom::new(store.clone(), |offset: Option<u64>| {
+    Just::new(offset.map_or(0, |offset| offset + 1))
+});
+let observable = observable.checkpoint(store, |offset| *offset);
+observable.subscribe_on_next(|offset| println!("processing {}", offset));
+```
+*/
+pub struct ResumeFrom<S, F> {
+    store: Arc<S>,
+    factory: Arc<F>,
+}
+
+impl<S, F> ResumeFrom<S, F> {
+    pub fn new(store: S, factory: F) -> ResumeFrom<S, F> {
+        ResumeFrom {
+            store: Arc::new(store),
+            factory: Arc::new(factory),
+        }
+    }
+}
+
+impl<S, F> Clone for ResumeFrom<S, F> {
+    fn clone(&self) -> Self {
+        ResumeFrom {
+            store: self.store.clone(),
+            factory: self.factory.clone(),
+        }
+    }
+}
+
+impl<T, E, S, F, O> Observable<T, E> for ResumeFrom<S, F>
+where
+    S: CheckpointStore,
+    F: Fn(Option<u64>) -> O + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        (self.factory)(self.store.load()).subscribe(observer)
+    }
+}
+
+/// Make the `Observable` save checkpoints.
+pub trait CheckpointObservable<T, E> {
+    /**
+    Saves the offset of each value to the store after the observer has processed it, so a pipeline restarted with `ResumeFrom` continues after the last processed value. A value that was being processed when the process stopped is delivered again, which gives at-least-once processing.
+
+    # Example
+    ```rust
+    use rx_rust::operators::checkpoint::{CheckpointObservable, CheckpointStore, MemoryCheckpointStore};
+    use rx_rust::operators::just::Just;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use std::sync::Arc;
+    let store = Arc::new(MemoryCheckpointStore::new());
+    let observable = Just::new(7).checkpoint(store.clone(), |offset| *offset);
+    observable.subscribe_on_next(|offset| println!("processing {}", offset));
+    assert_eq!(store.load(), Some(7));
+    ```
+     */
+    fn checkpoint<S>(
+        self,
+        store: S,
+        offset: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        S: CheckpointStore;
+}
+
+impl<O, T, E> CheckpointObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn checkpoint<S>(
+        self,
+        store: S,
+        offset: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        S: CheckpointStore,
+    {
+        Checkpoint::new(self, store, offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source(from: u64, to: u64) -> impl Observable<u64, String> {
+        Create::new(move |observer: Box<dyn Observer<u64, String>>| {
+            for offset in from..to {
+                observer.notify_if_unterminated(Event::Next(offset));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let observable = source(0, 3).checkpoint(store.clone(), |offset| *offset);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1, 2]));
+        assert!(checker.is_completed());
+        assert_eq!(store.load(), Some(2));
+    }
+
+    #[test]
+    fn test_saved_after_processed() {
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let store_cloned = store.clone();
+        let observable = source(5, 7).checkpoint(store.clone(), |offset| *offset);
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let saved_cloned = saved.clone();
+        observable.subscribe(AnonymousObserver::new(move |event: Event<u64, String>| {
+            if let Event::Next(_) = event {
+                saved_cloned.lock().unwrap().push(store_cloned.load());
+            }
+        }));
+        assert_eq!(*saved.lock().unwrap(), vec![None, Some(5)]);
+        assert_eq!(store.load(), Some(6));
+    }
+
+    #[test]
+    fn test_unsubscribed_mid_stream() {
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let checker = CheckingObserver::new();
+        let checker_cloned = checker.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<u64, String>>| {
+            for offset in 0..4 {
+                observer.notify_if_unterminated(Event::Next(offset));
+                if offset == 1 {
+                    // The downstream unsubscribes while the source keeps emitting.
+                    checker_cloned
+                        .notify_if_unterminated(Event::Terminated(Terminated::Unsubscribed));
+                }
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+        .checkpoint(store.clone(), |offset| *offset);
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1]));
+        assert!(checker.is_unsubscribed());
+        assert_eq!(store.load(), Some(1));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_resume_from() {
+        let store = Arc::new(MemoryCheckpointStore::new());
+        let observable = ResumeFrom::new(store.clone(), |offset: Option<u64>| {
+            source(offset.map_or(0, |offset| offset + 1), 5)
+        })
+        .checkpoint(store.clone(), |offset| *offset);
+
+        let checker = CheckingObserver::new();
+        let subscription = observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1, 2, 3, 4]));
+        drop(subscription);
+
+        store.save(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[3, 4]));
+        assert!(checker.is_completed());
+        assert_eq!(store.load(), Some(4));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+
+/// This is an observable that clones the referenced values of the source observable.
+#[derive(Clone)]
+pub struct Cloned<O> {
+    source: O,
+}
+
+impl<O> Cloned<O> {
+    pub fn new(source: O) -> Cloned<O> {
+        Cloned { source }
+    }
+}
+
+impl<T, E, O> Observable<T, E> for Cloned<O>
+where
+    O: Observable<&'static T, E>,
+    T: Clone + Sync + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = AnonymousObserver::new(move |event: Event<&'static T, E>| {
+            observer.notify_if_unterminated(event.map_value(T::clone))
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` of references clonable.
+pub trait ClonedObservable<T, E>
+where
+    T: Clone + Sync + 'static,
+{
+    /**
+    Clones the referenced values, so a source that lends its data can feed an observer that owns the values.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::cloned::ClonedObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    static NAMES: [&str; 2] = ["alice", "bob"];
+    let observable = Just::new(&NAMES);
+    let observable = observable.cloned();
+    observable.subscribe_on_next(|names: [&str; 2]| {
+        println!("{:?}", names);
+    });
+    ```
+     */
+    fn cloned(self) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> ClonedObservable<T, E> for O
+where
+    O: Observable<&'static T, E>,
+    T: Clone + Sync + 'static,
+{
+    fn cloned(self) -> impl Observable<T, E> {
+        Cloned::new(self)
+    }
+}
+
+/// Make the `Observable` of references copyable.
+pub trait CopiedObservable<T, E>
+where
+    T: Copy + Sync + 'static,
+{
+    /**
+    Copies the referenced values. It's `cloned` for `Copy` values.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::cloned::CopiedObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    static LIMIT: i32 = 333;
+    let observable = Just::new(&LIMIT);
+    let observable = observable.copied();
+    observable.subscribe_on_next(|value: i32| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn copied(self) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> CopiedObservable<T, E> for O
+where
+    O: Observable<&'static T, E>,
+    T: Copy + Sync + 'static,
+{
+    fn copied(self) -> impl Observable<T, E> {
+        Cloned::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    static VALUES: [i32; 3] = [1, 2, 3];
+    static NAMES: [&str; 2] = ["a", "b"];
+
+    fn source<T: Sync + 'static>(
+        values: &'static [T],
+        completed: bool,
+    ) -> impl Observable<&'static T, String> {
+        Create::new(move |observer: Box<dyn Observer<&'static T, String>>| {
+            for value in values {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            if completed {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_copied() {
+        let observable = source(&VALUES, true).copied();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_cloned() {
+        let observable = source(&NAMES, false).cloned();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&["a", "b"]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+}
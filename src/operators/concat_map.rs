@@ -0,0 +1,125 @@
+use super::flat_map::FlatMap;
+use crate::observable::Observable;
+
+/// Make the `Observable` concat-mappable.
+pub trait ConcatMapObservable<T, E> {
+    /**
+    Maps each value of the source observable to an inner observable, and subscribes to the inner observables strictly one at a time, in order. The source values are queued until the current inner observable completes.
+    It completes when the source and every inner observable have completed, and errors as soon as any of them errors. It is the same as `flat_map` with `max_concurrent(1)`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::concat_map::ConcatMapObservable;
+    use rx_rust::operators::range::Range;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.concat_map(|value| Range::new(0, value));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn concat_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static;
+}
+
+impl<O, T, E> ConcatMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn concat_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static,
+    {
+        FlatMap::new(self, f).max_concurrent(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::{event::Event, Observer},
+        operators::{
+            create::Create, delay::DelayableObservable, from_iter::FromIter, just::Just,
+            range::Range, throw::Throw,
+        },
+        scheduler::queue_scheduler::QueueScheduler,
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_in_order() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([3, 2, 1]).concat_map(move |value| {
+            Just::new(value).delay(
+                Duration::from_millis(value as u64 * 10),
+                scheduler_cloned.clone(),
+            )
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert_eq!(scheduler.pending(), 2);
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[3, 2, 1]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(60));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_many_synchronous_inners() {
+        let checker = CheckingObserver::new();
+        Range::new(0, 100_000)
+            .concat_map(Just::new)
+            .subscribe(checker.clone());
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_inner_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .concat_map(|value| Throw::new(format!("error {}", value)));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error 1".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe_drops_queue() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2, 3]).concat_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        assert!(checker.is_values_matched(&[1]));
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1]));
+        assert_eq!(scheduler.now(), Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+type StopAction = Box<dyn FnOnce() + Sync + Send + 'static>;
+
+struct ControllerState {
+    paused: bool,
+    next_id: u64,
+    stop_actions: HashMap<u64, StopAction>,
+}
+
+/**
+A handle that controls all the current subscriptions of a `Controllable` observable.
+
+# Example
+```rust
+use rx_rust::operators::just::Just;
+use rx_rust::operators::controllable::Controllable;
+let observable = Controllable::new(Just::new(333));
+let controller = observable.controller();
+controller.pause();
+assert!(controller.is_paused());
+controller.resume();
+controller.stop();
+```
+*/
+#[derive(Clone)]
+pub struct Controller {
+    state: Arc<Mutex<ControllerState>>,
+}
+
+impl Controller {
+    fn new() -> Controller {
+        Controller {
+            state: Arc::new(Mutex::new(ControllerState {
+                paused: false,
+                next_id: 0,
+                stop_actions: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Drop the values of all subscriptions until `resume` is called. The terminated events still pass.
+    pub fn pause(&self) {
+        self.state.lock().unwrap().paused = true;
+    }
+
+    /// Deliver the values again after `pause`.
+    pub fn resume(&self) {
+        self.state.lock().unwrap().paused = false;
+    }
+
+    /// Get whether the values are dropped.
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().paused
+    }
+
+    /// Complete all the current subscriptions and unsubscribe them from the source. Subscriptions made afterwards are not affected.
+    pub fn stop(&self) {
+        let stop_actions: Vec<StopAction> = self
+            .state
+            .lock()
+            .unwrap()
+            .stop_actions
+            .drain()
+            .map(|(_, action)| action)
+            .collect();
+        for action in stop_actions {
+            action();
+        }
+    }
+
+    fn register(&self, action: StopAction) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.stop_actions.insert(id, action);
+        id
+    }
+
+    fn unregister(&self, id: u64) {
+        let action = self.state.lock().unwrap().stop_actions.remove(&id);
+        drop(action);
+    }
+}
+
+/// This is an observable that forwards the source observable, and can be paused, resumed and stopped by its `Controller`.
+#[derive(Clone)]
+pub struct Controllable<O> {
+    source: O,
+    controller: Controller,
+}
+
+impl<O> Controllable<O> {
+    pub fn new(source: O) -> Controllable<O> {
+        Controllable {
+            source,
+            controller: Controller::new(),
+        }
+    }
+
+    /// The controller shared by this observable and its clones.
+    pub fn controller(&self) -> Controller {
+        self.controller.clone()
+    }
+}
+
+impl<T, E, O> Observable<T, E> for Controllable<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let controller = self.controller.clone();
+        let observer = Arc::new(observer);
+        let source_subscription: Arc<Mutex<Option<Subscription>>> = Arc::new(Mutex::new(None));
+        let observer_cloned = observer.clone();
+        let source_subscription_cloned = source_subscription.clone();
+        let id = controller.register(Box::new(move || {
+            observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            let subscription = source_subscription_cloned.lock().unwrap().take();
+            drop(subscription);
+        }));
+        let observer_cloned = observer.clone();
+        let controller_cloned = controller.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(_) => {
+                if !controller_cloned.is_paused() {
+                    observer_cloned.notify_if_unterminated(event);
+                }
+            }
+            Event::Terminated(_) => {
+                controller_cloned.unregister(id);
+                observer_cloned.notify_if_unterminated(event);
+            }
+        });
+        let subscription = self.source.subscribe(source_observer);
+        if observer.terminated() {
+            drop(subscription);
+        } else {
+            *source_subscription.lock().unwrap() = Some(subscription);
+        }
+        Subscription::new(observer, move || {
+            controller.unregister(id);
+            let subscription = source_subscription.lock().unwrap().take();
+            drop(subscription);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    type SourceObservers = Arc<Mutex<Vec<Arc<Box<dyn Observer<i32, String>>>>>>;
+
+    fn source() -> (impl Observable<i32, String>, SourceObservers) {
+        let observers = Arc::new(Mutex::new(Vec::new()));
+        let observers_cloned = observers.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            let observer = Arc::new(observer);
+            observers_cloned.lock().unwrap().push(observer.clone());
+            Subscription::new_non_disposal_action(observer)
+        });
+        (observable, observers)
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = Controllable::new(Just::new(333));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let (observable, observers) = source();
+        let observable = Controllable::new(observable);
+        let controller = observable.controller();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        let emit = |value| {
+            for observer in observers.lock().unwrap().iter() {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+        };
+        emit(1);
+        controller.pause();
+        emit(2);
+        controller.resume();
+        emit(3);
+        assert!(checker.is_values_matched(&[1, 3]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_stop() {
+        let (observable, observers) = source();
+        let observable = Controllable::new(observable);
+        let controller = observable.controller();
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = observable.clone().subscribe(checker2.clone());
+        controller.stop();
+        assert!(checker1.is_completed());
+        assert!(checker2.is_completed());
+        for observer in observers.lock().unwrap().iter() {
+            assert!(observer.terminated());
+        }
+
+        let checker3 = CheckingObserver::new();
+        let subscription3 = observable.subscribe(checker3.clone());
+        assert!(checker3.is_unterminated());
+        _ = (subscription1, subscription2, subscription3); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let (observable, _) = source();
+        let observable = Controllable::new(observable);
+        let controller = observable.controller();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        controller.stop();
+        assert!(checker.is_unsubscribed());
+    }
+}
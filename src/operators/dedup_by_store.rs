@@ -0,0 +1,264 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// A `DedupStore` records the keys that have been processed.
+/// The store must be Sync and Send because it will be used in multiple threads.
+/// The store must be 'static because it will be stored in the observable.
+pub trait DedupStore<K>: Sync + Send + 'static {
+    /// Record the key. Returns true if the key has not been recorded before.
+    fn insert_if_absent(&self, key: K) -> bool;
+}
+
+impl<K, S> DedupStore<K> for Arc<S>
+where
+    S: DedupStore<K>,
+{
+    fn insert_if_absent(&self, key: K) -> bool {
+        self.as_ref().insert_if_absent(key)
+    }
+}
+
+/**
+An in-memory `DedupStore` that remembers the most recently used keys up to a capacity.
+
+# Example
+```rust
+use rx_rust::operators::dedup_by_store::{DedupStore, LruDedupStore};
+let store = LruDedupStore::new(2);
+assert!(store.insert_if_absent(1));
+assert!(!store.insert_if_absent(1));
+```
+*/
+pub struct LruDedupStore<K> {
+    capacity: usize,
+    entries: Mutex<LruEntries<K>>,
+}
+
+struct LruEntries<K> {
+    next_stamp: u64,
+    stamps: HashMap<K, u64>,
+    keys: BTreeMap<u64, K>,
+}
+
+impl<K> LruDedupStore<K> {
+    pub fn new(capacity: usize) -> LruDedupStore<K> {
+        LruDedupStore {
+            capacity,
+            entries: Mutex::new(LruEntries {
+                next_stamp: 0,
+                stamps: HashMap::new(),
+                keys: BTreeMap::new(),
+            }),
+        }
+    }
+}
+
+impl<K> DedupStore<K> for LruDedupStore<K>
+where
+    K: Eq + Hash + Clone + Sync + Send + 'static,
+{
+    fn insert_if_absent(&self, key: K) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        let stamp = entries.next_stamp;
+        entries.next_stamp += 1;
+        if let Some(old_stamp) = entries.stamps.insert(key.clone(), stamp) {
+            entries.keys.remove(&old_stamp);
+            entries.keys.insert(stamp, key);
+            return false;
+        }
+        entries.keys.insert(stamp, key);
+        while entries.keys.len() > self.capacity {
+            if let Some((_, oldest)) = entries.keys.pop_first() {
+                entries.stamps.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// This is an observable that drops the values whose key has already been recorded in the store.
+pub struct DedupByStore<K, O, F, S> {
+    source: O,
+    key_selector: Arc<F>,
+    store: Arc<S>,
+    _marker: PhantomData<K>,
+}
+
+impl<K, O, F, S> DedupByStore<K, O, F, S> {
+    pub fn new(source: O, key_selector: F, store: S) -> DedupByStore<K, O, F, S> {
+        DedupByStore {
+            source,
+            key_selector: Arc::new(key_selector),
+            store: Arc::new(store),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, O, F, S> Clone for DedupByStore<K, O, F, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        DedupByStore {
+            source: self.source.clone(),
+            key_selector: self.key_selector.clone(),
+            store: self.store.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, K, O, F, S> Observable<T, E> for DedupByStore<K, O, F, S>
+where
+    O: Observable<T, E>,
+    F: Fn(&T) -> K + Sync + Send + 'static,
+    K: Sync + Send + 'static,
+    S: DedupStore<K>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let key_selector = self.key_selector.clone();
+        let store = self.store.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                if store.insert_if_absent(key_selector(&value)) {
+                    observer.notify_if_unterminated(Event::Next(value));
+                }
+            }
+            Event::Terminated(_) => observer.notify_if_unterminated(event),
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` deduplicatable by a `DedupStore`.
+pub trait DedupByStoreObservable<T, E> {
+    /**
+    Drops the values whose key has already been recorded in the store. The store is shared by all subscriptions.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::dedup_by_store::{DedupByStoreObservable, LruDedupStore};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.dedup_by_store(|value| *value, LruDedupStore::new(1024));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn dedup_by_store<K, S>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+        store: S,
+    ) -> impl Observable<T, E>
+    where
+        K: Sync + Send + 'static,
+        S: DedupStore<K>;
+}
+
+impl<O, T, E> DedupByStoreObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn dedup_by_store<K, S>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+        store: S,
+    ) -> impl Observable<T, E>
+    where
+        K: Sync + Send + 'static,
+        S: DedupStore<K>,
+    {
+        DedupByStore::new(self, key_selector, store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_lru_store() {
+        let store = LruDedupStore::new(2);
+        assert!(store.insert_if_absent(1));
+        assert!(store.insert_if_absent(2));
+        assert!(!store.insert_if_absent(1));
+        assert!(store.insert_if_absent(3));
+        // 2 is the least recently used key, so it was evicted.
+        assert!(store.insert_if_absent(2));
+        assert!(!store.insert_if_absent(3));
+    }
+
+    #[test]
+    fn test_dedup() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(3));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.dedup_by_store(|value| *value, LruDedupStore::new(10));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.dedup_by_store(|value| *value, LruDedupStore::new(10));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_shared_store() {
+        let store = Arc::new(LruDedupStore::new(10));
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            Subscription::new_non_disposal_action(observer)
+        });
+
+        let checker = CheckingObserver::new();
+        let subscription1 = observable
+            .clone()
+            .dedup_by_store(|value| *value, store.clone())
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+
+        let checker = CheckingObserver::new();
+        let subscription2 = observable
+            .dedup_by_store(|value| *value, store)
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        _ = subscription1; // keep the subscription alive
+        _ = subscription2; // keep the subscription alive
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{observable::Observable, observer::Observer, subscription::Subscription};
+use std::sync::Arc;
+
+/**
+This is an observable that calls the factory for each subscription, and subscribes the observer to the observable it returns. It lets each subscription have its own state, e.g. a timestamp or a counter.
+
+# Example
+```rust
+use rx_rust::operators::defer::Defer;
+use rx_rust::operators::just::Just;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use std::time::Instant;
+let observable = Defer::new(|| Just::new(Instant::now()));
+observable.subscribe_on_next(|subscribed_at| println!("{:?}", subscribed_at));
+```
+*/
+pub struct Defer<F> {
+    factory: Arc<F>,
+}
+
+impl<F> Defer<F> {
+    pub fn new(factory: F) -> Defer<F> {
+        Defer {
+            factory: Arc::new(factory),
+        }
+    }
+}
+
+impl<F> Clone for Defer<F> {
+    fn clone(&self) -> Self {
+        Defer {
+            factory: self.factory.clone(),
+        }
+    }
+}
+
+impl<T, E, O, F> Observable<T, E> for Defer<F>
+where
+    F: Fn() -> O + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        (self.factory)().subscribe(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::just::Just, utils::checking_observer::CheckingObserver};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_factory_per_subscription() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_cloned = count.clone();
+        let observable = Defer::new(move || Just::new(count_cloned.fetch_add(1, Ordering::SeqCst)));
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0]));
+        assert!(checker.is_completed());
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+}
@@ -96,7 +96,7 @@ pub trait DelayableObservable<T, E> {
     #[tokio::main]
     async fn main() {
         let observable = Just::new(333);
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
         observable.subscribe_on_event(|event| {
             println!("{:?}", event);
         });
@@ -156,7 +156,7 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
         let checker = CheckingObserver::new();
         let subscription = observable.subscribe(checker.clone());
         assert!(checker.is_values_matched(&[]));
@@ -203,7 +203,7 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
         let checker = CheckingObserver::new();
         let subscription = observable.subscribe(checker.clone());
         assert!(checker.is_values_matched(&[]));
@@ -243,7 +243,7 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
         let checker = CheckingObserver::new();
         let subscription = observable.subscribe(checker.clone());
         tokio::spawn(async move {
@@ -286,7 +286,7 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
         let checker = CheckingObserver::new();
         let subscription = observable.subscribe(checker.clone());
         assert!(checker.is_values_matched(&[]));
@@ -331,7 +331,7 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(10), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(10), TokioScheduler::new());
 
         let checker1 = CheckingObserver::new();
         let subscription1 = observable.clone().subscribe(checker1.clone());
@@ -393,8 +393,8 @@ mod tests {
             });
             Subscription::new_non_disposal_action(observer)
         });
-        let observable = observable.delay(Duration::from_millis(5), TokioScheduler);
-        let observable = observable.delay(Duration::from_millis(5), TokioScheduler);
+        let observable = observable.delay(Duration::from_millis(5), TokioScheduler::new());
+        let observable = observable.delay(Duration::from_millis(5), TokioScheduler::new());
         let checker = CheckingObserver::new();
         let subscription = observable.subscribe(checker.clone());
         assert!(checker.is_values_matched(&[]));
@@ -0,0 +1,191 @@
+use super::materialize::Notification;
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that turns the `Notification` values of the source observable back into events: the values are emitted, and the first `Completed` or `Error` notification terminates it and unsubscribes the source.
+#[derive(Clone)]
+pub struct Dematerialize<O> {
+    source: O,
+}
+
+impl<O> Dematerialize<O> {
+    pub fn new(source: O) -> Dematerialize<O> {
+        Dematerialize { source }
+    }
+}
+
+struct DematerializeState {
+    stopped: bool,
+    source_subscription: Option<Subscription>,
+}
+
+impl DematerializeState {
+    /// Stop and take the source subscription, so it can be dropped outside the lock.
+    fn stop(&mut self) -> Option<Subscription> {
+        self.stopped = true;
+        self.source_subscription.take()
+    }
+}
+
+impl<T, E, O> Observable<T, E> for Dematerialize<O>
+where
+    O: Observable<Notification<T, E>, Infallible>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(DematerializeState {
+            stopped: false,
+            source_subscription: None,
+        }));
+        let observer_cloned = observer.clone();
+        let state_cloned = state.clone();
+        let source_observer =
+            AnonymousObserver::new(move |event: Event<Notification<T, E>, Infallible>| {
+                let terminated = match event {
+                    Event::Next(Notification::Next(value)) => {
+                        observer_cloned.notify_if_unterminated(Event::Next(value));
+                        return;
+                    }
+                    Event::Next(Notification::Completed) => Terminated::Completed,
+                    Event::Next(Notification::Error(error)) => Terminated::Error(error),
+                    Event::Terminated(terminated) => {
+                        observer_cloned.notify_if_unterminated(Event::Terminated(
+                            terminated.map_err(|never| match never {}),
+                        ));
+                        return;
+                    }
+                };
+                // The stream ends with the notification, so the source is not needed anymore.
+                let subscription = state_cloned.lock().unwrap().stop();
+                observer_cloned.notify_if_unterminated(Event::Terminated(terminated));
+                drop(subscription);
+            });
+        let subscription = self.source.subscribe(source_observer);
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.stopped {
+            drop(state_guard);
+            drop(subscription);
+        } else {
+            state_guard.source_subscription = Some(subscription);
+            drop(state_guard);
+        }
+        Subscription::new(observer, move || {
+            let subscription = state.lock().unwrap().stop();
+            drop(subscription);
+        })
+    }
+}
+
+/// Make the `Observable` of `Notification` values dematerializable.
+pub trait DematerializeObservable<T, E> {
+    /**
+    Turns the `Notification` values back into events, replaying the `Completed` and `Error` notifications as real terminated events. It is the inverse of `materialize`, e.g. to replay a recorded flow.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::dematerialize::DematerializeObservable;
+    use rx_rust::operators::materialize::Notification;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let recorded = vec![
+        Notification::Next(333),
+        Notification::Error("error".to_owned()),
+    ];
+    let observable = FromIter::new(recorded).dematerialize();
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn dematerialize(self) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> DematerializeObservable<T, E> for O
+where
+    O: Observable<Notification<T, E>, Infallible>,
+{
+    fn dematerialize(self) -> impl Observable<T, E> {
+        Dematerialize::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable,
+        operators::{create::Create, from_iter::FromIter, materialize::MaterializeObservable},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_error() {
+        let observable = FromIter::new(vec![
+            Notification::Next(1),
+            Notification::Error("error".to_owned()),
+            Notification::Next(2),
+        ])
+        .dematerialize();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe_source_on_terminated_notification() {
+        for notification in [
+            Notification::Completed,
+            Notification::Error("error".to_owned()),
+        ] {
+            let (emitter, observable) = HotObservable::<Notification<i32, String>, _>::new();
+            let checker = CheckingObserver::new();
+            let subscription = observable.dematerialize().subscribe(checker.clone());
+            emitter.emit(Notification::Next(1));
+            assert_eq!(emitter.observer_count(), 1);
+            emitter.emit(notification);
+            assert_eq!(emitter.observer_count(), 0);
+            emitter.emit(Notification::Next(2));
+            assert!(checker.is_values_matched(&[1]));
+            assert!(!checker.is_unterminated());
+            _ = subscription; // keep the subscription alive
+        }
+    }
+
+    #[test]
+    fn test_source_completed() {
+        let observable = FromIter::new(vec![Notification::<i32, String>::Next(1)]).dematerialize();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .materialize()
+        .dematerialize();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+}
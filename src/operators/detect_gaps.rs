@@ -0,0 +1,391 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// A skipped or regressed sequence number. `expected` is the number following the previous value, `received` is the number of the current value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceGap {
+    pub expected: u64,
+    pub received: u64,
+}
+
+impl SequenceGap {
+    /// Get whether the sequence number went backwards or repeated, instead of skipping forward.
+    pub fn is_regression(&self) -> bool {
+        self.received < self.expected
+    }
+}
+
+/// A value emitted by `detect_gaps`: either a value of the source observable, or a gap report emitted right before the value that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sequenced<T> {
+    Value(T),
+    Gap(SequenceGap),
+}
+
+/// The error of an `error_on_gaps` observable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GapError<E> {
+    /// The sequence numbers were not contiguous.
+    Gap(SequenceGap),
+    /// The source observable terminated with an error.
+    Source(E),
+}
+
+struct SequenceTracker<F> {
+    sequence_number: Arc<F>,
+    expected: Mutex<Option<u64>>,
+}
+
+impl<F> SequenceTracker<F> {
+    fn check<T>(&self, value: &T) -> Option<SequenceGap>
+    where
+        F: Fn(&T) -> u64,
+    {
+        let received = (self.sequence_number)(value);
+        let mut expected = self.expected.lock().unwrap();
+        let gap = expected
+            .filter(|expected| *expected != received)
+            .map(|expected| SequenceGap { expected, received });
+        *expected = Some(received.wrapping_add(1));
+        gap
+    }
+}
+
+/// This is an observable that watches the sequence numbers of the values of the source observable, and emits a `Sequenced::Gap` before each value whose number does not follow the previous one.
+pub struct DetectGaps<T, O, F> {
+    source: O,
+    sequence_number: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> DetectGaps<T, O, F> {
+    pub fn new(source: O, sequence_number: F) -> DetectGaps<T, O, F> {
+        DetectGaps {
+            source,
+            sequence_number: Arc::new(sequence_number),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for DetectGaps<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        DetectGaps {
+            source: self.source.clone(),
+            sequence_number: self.sequence_number.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, O, F> Observable<Sequenced<T>, E> for DetectGaps<T, O, F>
+where
+    T: Sync + Send + 'static,
+    F: Fn(&T) -> u64 + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<Sequenced<T>, E>) -> Subscription {
+        let tracker = SequenceTracker {
+            sequence_number: self.sequence_number.clone(),
+            expected: Mutex::new(None),
+        };
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                if let Some(gap) = tracker.check(&value) {
+                    observer.notify_if_unterminated(Event::Next(Sequenced::Gap(gap)));
+                }
+                observer.notify_if_unterminated(Event::Next(Sequenced::Value(value)));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// This is an observable that watches the sequence numbers of the values of the source observable, and terminates with `GapError::Gap` on the first value whose number does not follow the previous one.
+pub struct ErrorOnGaps<T, O, F> {
+    source: O,
+    sequence_number: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> ErrorOnGaps<T, O, F> {
+    pub fn new(source: O, sequence_number: F) -> ErrorOnGaps<T, O, F> {
+        ErrorOnGaps {
+            source,
+            sequence_number: Arc::new(sequence_number),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for ErrorOnGaps<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        ErrorOnGaps {
+            source: self.source.clone(),
+            sequence_number: self.sequence_number.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct ErrorOnGapsState {
+    stopped: bool,
+    source_subscription: Option<Subscription>,
+}
+
+impl ErrorOnGapsState {
+    /// Stop and take the source subscription, so it can be dropped outside the lock.
+    fn stop(&mut self) -> Option<Subscription> {
+        self.stopped = true;
+        self.source_subscription.take()
+    }
+}
+
+impl<T, E, O, F> Observable<T, GapError<E>> for ErrorOnGaps<T, O, F>
+where
+    T: Sync + Send + 'static,
+    F: Fn(&T) -> u64 + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, GapError<E>>) -> Subscription {
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(ErrorOnGapsState {
+            stopped: false,
+            source_subscription: None,
+        }));
+        let tracker = SequenceTracker {
+            sequence_number: self.sequence_number.clone(),
+            expected: Mutex::new(None),
+        };
+        let observer_cloned = observer.clone();
+        let state_cloned = state.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => match tracker.check(&value) {
+                Some(gap) => {
+                    let subscription = state_cloned.lock().unwrap().stop();
+                    observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                        GapError::Gap(gap),
+                    )));
+                    drop(subscription);
+                }
+                None => observer_cloned.notify_if_unterminated(Event::Next(value)),
+            },
+            Event::Terminated(terminated) => observer_cloned
+                .notify_if_unterminated(Event::Terminated(terminated).map_error(GapError::Source)),
+        });
+        let subscription = self.source.subscribe(source_observer);
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.stopped {
+            drop(state_guard);
+            drop(subscription);
+        } else {
+            state_guard.source_subscription = Some(subscription);
+            drop(state_guard);
+        }
+        Subscription::new(observer, move || {
+            let subscription = state.lock().unwrap().stop();
+            drop(subscription);
+        })
+    }
+}
+
+/// Make the `Observable` check the sequence numbers of its values.
+pub trait DetectGapsObservable<T, E> {
+    /**
+    Reads a sequence number from each value, and emits a `Sequenced::Gap` report before a value whose number is not the previous number plus one. The values are emitted as `Sequenced::Value`. The check continues from the number of the current value, so a gap is reported once.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::detect_gaps::{DetectGapsObservable, Sequenced};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new((7, "tick"));
+    let observable = observable.detect_gaps(|(sequence_number, _)| *sequence_number);
+    observable.subscribe_on_next(|item| match item {
+        Sequenced::Value((_, value)) => println!("{}", value),
+        Sequenced::Gap(gap) => println!("expected {}, received {}", gap.expected, gap.received),
+    });
+    ```
+     */
+    fn detect_gaps(
+        self,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<Sequenced<T>, E>
+    where
+        T: Sync + Send + 'static;
+
+    /**
+    Reads a sequence number from each value, and terminates with `GapError::Gap` on the first value whose number is not the previous number plus one. The errors of the source are wrapped in `GapError::Source`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::detect_gaps::DetectGapsObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new((7, "tick"));
+    let observable = observable.error_on_gaps(|(sequence_number, _)| *sequence_number);
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn error_on_gaps(
+        self,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<T, GapError<E>>
+    where
+        T: Sync + Send + 'static;
+}
+
+impl<O, T, E> DetectGapsObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn detect_gaps(
+        self,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<Sequenced<T>, E>
+    where
+        T: Sync + Send + 'static,
+    {
+        DetectGaps::new(self, sequence_number)
+    }
+
+    fn error_on_gaps(
+        self,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<T, GapError<E>>
+    where
+        T: Sync + Send + 'static,
+    {
+        ErrorOnGaps::new(self, sequence_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source(values: Vec<u64>) -> impl Observable<u64, String> {
+        Create::new(move |observer: Box<dyn Observer<u64, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    fn gap(expected: u64, received: u64) -> SequenceGap {
+        SequenceGap { expected, received }
+    }
+
+    #[test]
+    fn test_detect_gaps() {
+        let observable = source(vec![3, 4, 6, 7, 5, 6]).detect_gaps(|value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(3),
+            Sequenced::Value(4),
+            Sequenced::Gap(gap(5, 6)),
+            Sequenced::Value(6),
+            Sequenced::Value(7),
+            Sequenced::Gap(gap(8, 5)),
+            Sequenced::Value(5),
+            Sequenced::Value(6),
+        ]));
+        assert!(checker.is_completed());
+        assert!(!gap(5, 6).is_regression());
+        assert!(gap(8, 5).is_regression());
+    }
+
+    #[test]
+    fn test_error_on_gaps() {
+        let observable = source(vec![1, 2, 2, 3]).error_on_gaps(|value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error(GapError::Gap(gap(3, 2))));
+    }
+
+    #[test]
+    fn test_unsubscribe_source_on_gap() {
+        let (emitter, observable) = HotObservable::<u64, String>::new();
+        let observable = observable.error_on_gaps(|value| *value);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        assert_eq!(emitter.observer_count(), 1);
+        emitter.emit(3);
+        assert_eq!(emitter.observer_count(), 0);
+        emitter.emit(4);
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error(GapError::Gap(gap(2, 3))));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error_on_gaps_completed() {
+        let observable = source(vec![1, 2, 3]).error_on_gaps(|value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_source_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<u64, String>>| {
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .error_on_gaps(|value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_error(GapError::Source("error".to_owned())));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = source(vec![1, 3]).detect_gaps(|value| *value);
+        let expected = [
+            Sequenced::Value(1),
+            Sequenced::Gap(gap(2, 3)),
+            Sequenced::Value(3),
+        ];
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&expected));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&expected));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// A change between two snapshots, emitted by `diff_snapshots`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change<K, V> {
+    Added(K, V),
+    Updated(K, V),
+    Removed(K),
+}
+
+/// A `Snapshot` is a collection that can be compared with its previous version.
+pub trait Snapshot: Default {
+    type Key;
+    type Value;
+
+    /// The changes that turn `previous` into `self`.
+    fn changes_since(&self, previous: &Self) -> Vec<Change<Self::Key, Self::Value>>;
+}
+
+/// The changes between the entries of two maps: the entries of `current` are looked up with `previous_get`, and the keys of `previous` with `current_contains`.
+fn map_changes<'a, K, V>(
+    current: impl Iterator<Item = (&'a K, &'a V)>,
+    previous_keys: impl Iterator<Item = &'a K>,
+    current_contains: impl Fn(&K) -> bool,
+    previous_get: impl Fn(&K) -> Option<&'a V>,
+) -> Vec<Change<K, V>>
+where
+    K: Clone + 'a,
+    V: PartialEq + Clone + 'a,
+{
+    let mut changes: Vec<Change<K, V>> = current
+        .filter_map(|(key, value)| match previous_get(key) {
+            None => Some(Change::Added(key.clone(), value.clone())),
+            Some(old) if old != value => Some(Change::Updated(key.clone(), value.clone())),
+            Some(_) => None,
+        })
+        .collect();
+    changes.extend(
+        previous_keys
+            .filter(|key| !current_contains(key))
+            .map(|key| Change::Removed(key.clone())),
+    );
+    changes
+}
+
+impl<K, V> Snapshot for HashMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: PartialEq + Clone,
+{
+    type Key = K;
+    type Value = V;
+
+    fn changes_since(&self, previous: &Self) -> Vec<Change<K, V>> {
+        map_changes(
+            self.iter(),
+            previous.keys(),
+            |key| self.contains_key(key),
+            |key| previous.get(key),
+        )
+    }
+}
+
+impl<K, V> Snapshot for BTreeMap<K, V>
+where
+    K: Ord + Clone,
+    V: PartialEq + Clone,
+{
+    type Key = K;
+    type Value = V;
+
+    fn changes_since(&self, previous: &Self) -> Vec<Change<K, V>> {
+        map_changes(
+            self.iter(),
+            previous.keys(),
+            |key| self.contains_key(key),
+            |key| previous.get(key),
+        )
+    }
+}
+
+/// A `Vec` is compared by index.
+impl<T> Snapshot for Vec<T>
+where
+    T: PartialEq + Clone,
+{
+    type Key = usize;
+    type Value = T;
+
+    fn changes_since(&self, previous: &Self) -> Vec<Change<usize, T>> {
+        let mut changes: Vec<Change<usize, T>> = self
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| match previous.get(index) {
+                None => Some(Change::Added(index, value.clone())),
+                Some(old) if old != value => Some(Change::Updated(index, value.clone())),
+                Some(_) => None,
+            })
+            .collect();
+        changes.extend((self.len()..previous.len()).map(Change::Removed));
+        changes
+    }
+}
+
+impl<S> Snapshot for Arc<S>
+where
+    S: Snapshot,
+{
+    type Key = S::Key;
+    type Value = S::Value;
+
+    fn changes_since(&self, previous: &Self) -> Vec<Change<S::Key, S::Value>> {
+        self.as_ref().changes_since(previous.as_ref())
+    }
+}
+
+/// This is an observable that compares each snapshot of the source observable with the previous one, and emits the changes one by one. The first snapshot is compared with an empty one.
+pub struct DiffSnapshots<S, O> {
+    source: O,
+    _marker: PhantomData<S>,
+}
+
+impl<S, O> DiffSnapshots<S, O> {
+    pub fn new(source: O) -> DiffSnapshots<S, O> {
+        DiffSnapshots {
+            source,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, O> Clone for DiffSnapshots<S, O>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        DiffSnapshots {
+            source: self.source.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, E, O> Observable<Change<S::Key, S::Value>, E> for DiffSnapshots<S, O>
+where
+    O: Observable<S, E>,
+    S: Snapshot + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Change<S::Key, S::Value>, E>) -> Subscription {
+        let previous = Mutex::new(S::default());
+        let observer = AnonymousObserver::new(move |event: Event<S, E>| match event {
+            Event::Next(snapshot) => {
+                let mut previous = previous.lock().unwrap();
+                let changes = snapshot.changes_since(&previous);
+                *previous = snapshot;
+                drop(previous);
+                for change in changes {
+                    observer.notify_if_unterminated(Event::Next(change));
+                }
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` of snapshots emit the changes between them.
+pub trait DiffSnapshotsObservable<S, E>
+where
+    S: Snapshot,
+{
+    /**
+    Compares each snapshot with the previous one, and emits the `Change`s one by one, so a source of full snapshots can feed an incremental consumer. The first snapshot is compared with an empty one, so all its entries are added.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::diff_snapshots::{Change, DiffSnapshotsObservable};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use std::collections::HashMap;
+    let observable = Just::new(HashMap::from([("sensor-1", 21)]));
+    let observable = observable.diff_snapshots();
+    observable.subscribe_on_next(|change| match change {
+        Change::Added(key, value) | Change::Updated(key, value) => println!("{} = {}", key, value),
+        Change::Removed(key) => println!("{} removed", key),
+    });
+    ```
+     */
+    fn diff_snapshots(self) -> impl Observable<Change<S::Key, S::Value>, E>
+    where
+        S: Sync + Send + 'static;
+}
+
+impl<O, S, E> DiffSnapshotsObservable<S, E> for O
+where
+    O: Observable<S, E>,
+    S: Snapshot,
+{
+    fn diff_snapshots(self) -> impl Observable<Change<S::Key, S::Value>, E>
+    where
+        S: Sync + Send + 'static,
+    {
+        DiffSnapshots::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source<S>(snapshots: Vec<S>) -> impl Observable<S, String>
+    where
+        S: Clone + Sync + Send + 'static,
+    {
+        Create::new(move |observer: Box<dyn Observer<S, String>>| {
+            for snapshot in snapshots.iter() {
+                observer.notify_if_unterminated(Event::Next(snapshot.clone()));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_map() {
+        let observable = source(vec![
+            BTreeMap::from([("a", 1), ("b", 2)]),
+            BTreeMap::from([("a", 1), ("b", 3), ("c", 4)]),
+            BTreeMap::from([("c", 4)]),
+        ])
+        .diff_snapshots();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Change::Added("a", 1),
+            Change::Added("b", 2),
+            Change::Updated("b", 3),
+            Change::Added("c", 4),
+            Change::Removed("a"),
+            Change::Removed("b"),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_hash_map() {
+        let observable = source(vec![
+            Arc::new(HashMap::from([("a", 1)])),
+            Arc::new(HashMap::from([("a", 2)])),
+            Arc::new(HashMap::new()),
+        ])
+        .diff_snapshots();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Change::Added("a", 1),
+            Change::Updated("a", 2),
+            Change::Removed("a"),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_vec() {
+        let observable = source(vec![vec![1, 2], vec![1, 5, 6], vec![0]]).diff_snapshots();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Change::Added(0, 1),
+            Change::Added(1, 2),
+            Change::Updated(1, 5),
+            Change::Added(2, 6),
+            Change::Updated(0, 0),
+            Change::Removed(1),
+            Change::Removed(2),
+        ]));
+        assert!(checker.is_completed());
+    }
+}
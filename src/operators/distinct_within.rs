@@ -0,0 +1,316 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// This is an observable that suppresses the values whose key is equal to the key of any value emitted within the last window. Each emitted key is evicted after the window elapses on the scheduler.
+pub struct DistinctWithin<K, O, F, S> {
+    source: O,
+    key_selector: Arc<F>,
+    window: Duration,
+    scheduler: Arc<S>,
+    _marker: PhantomData<K>,
+}
+
+impl<K, O, F, S> DistinctWithin<K, O, F, S> {
+    pub fn new(source: O, key_selector: F, window: Duration, scheduler: S) -> Self {
+        DistinctWithin {
+            source,
+            key_selector: Arc::new(key_selector),
+            window,
+            scheduler: Arc::new(scheduler),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, O, F, S> Clone for DistinctWithin<K, O, F, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        DistinctWithin {
+            source: self.source.clone(),
+            key_selector: self.key_selector.clone(),
+            window: self.window,
+            scheduler: self.scheduler.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+type Eviction = Disposal<Box<dyn FnOnce() + Send>>;
+
+struct DistinctWithinState<K> {
+    next_id: u64,
+    /// The keys emitted within the window, with the id of their eviction.
+    seen: HashMap<K, u64>,
+    /// The pending evictions, `None` while the eviction is being scheduled.
+    evictions: HashMap<u64, Option<Eviction>>,
+}
+
+impl<T, E, K, O, F, S> Observable<T, E> for DistinctWithin<K, O, F, S>
+where
+    O: Observable<T, E>,
+    F: Fn(&T) -> K + Sync + Send + 'static,
+    K: Hash + Eq + Clone + Sync + Send + 'static,
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let key_selector = self.key_selector.clone();
+        let scheduler = self.scheduler.clone();
+        let window = self.window;
+        let state = Arc::new(Mutex::new(DistinctWithinState {
+            next_id: 0,
+            seen: HashMap::new(),
+            evictions: HashMap::new(),
+        }));
+        let state_cloned = state.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            let value = match event {
+                Event::Next(value) => value,
+                Event::Terminated(_) => {
+                    observer.notify_if_unterminated(event);
+                    return;
+                }
+            };
+            let key = key_selector(&value);
+            let id = {
+                let mut state = state.lock().unwrap();
+                if state.seen.contains_key(&key) {
+                    return;
+                }
+                let id = state.next_id;
+                state.next_id += 1;
+                state.seen.insert(key.clone(), id);
+                state.evictions.insert(id, None);
+                id
+            };
+            let state_for_eviction = state.clone();
+            let eviction = scheduler.schedule(
+                move || {
+                    let mut state = state_for_eviction.lock().unwrap();
+                    state.seen.remove(&key);
+                    let eviction = state.evictions.remove(&id);
+                    drop(state);
+                    drop(eviction);
+                },
+                Some(window),
+            );
+            let eviction = eviction.to_boxed();
+            let mut state_guard = state.lock().unwrap();
+            if let Some(slot) = state_guard.evictions.get_mut(&id) {
+                *slot = Some(eviction);
+                drop(state_guard);
+            } else {
+                // The eviction has already run.
+                drop(state_guard);
+                drop(eviction);
+            }
+            observer.notify_if_unterminated(Event::Next(value));
+        });
+        let subscription = self.source.subscribe(observer);
+        subscription.insert_disposal_action(move || {
+            let evictions: Vec<_> = state_cloned
+                .lock()
+                .unwrap()
+                .evictions
+                .drain()
+                .filter_map(|(_, eviction)| eviction)
+                .collect();
+            for eviction in evictions {
+                eviction.dispose();
+            }
+        })
+    }
+}
+
+/// Make the `Observable` deduplicatable within a time window.
+pub trait DistinctWithinObservable<T, E> {
+    /**
+    Suppresses the values equal to any value emitted within the last window.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::distinct_within::DistinctWithinObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new(333);
+        let observable = observable.distinct_within(Duration::from_secs(1), TokioScheduler::new());
+        observable.subscribe_on_event(|event| {
+            println!("{:?}", event);
+        });
+    }
+    ```
+     */
+    fn distinct_within<S>(self, window: Duration, scheduler: S) -> impl Observable<T, E>
+    where
+        T: Clone + Hash + Eq + Sync + Send + 'static,
+        S: Scheduler;
+
+    /**
+    Suppresses the values whose key is equal to the key of any value emitted within the last window.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::distinct_within::DistinctWithinObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new((1, "message"));
+        let observable =
+            observable.distinct_within_by_key(|(id, _)| *id, Duration::from_secs(1), TokioScheduler::new());
+        observable.subscribe_on_event(|event| {
+            println!("{:?}", event);
+        });
+    }
+    ```
+     */
+    fn distinct_within_by_key<K, S>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+        window: Duration,
+        scheduler: S,
+    ) -> impl Observable<T, E>
+    where
+        K: Hash + Eq + Clone + Sync + Send + 'static,
+        S: Scheduler;
+}
+
+impl<O, T, E> DistinctWithinObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn distinct_within<S>(self, window: Duration, scheduler: S) -> impl Observable<T, E>
+    where
+        T: Clone + Hash + Eq + Sync + Send + 'static,
+        S: Scheduler,
+    {
+        DistinctWithin::new(self, |value: &T| value.clone(), window, scheduler)
+    }
+
+    fn distinct_within_by_key<K, S>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+        window: Duration,
+        scheduler: S,
+    ) -> impl Observable<T, E>
+    where
+        K: Hash + Eq + Clone + Sync + Send + 'static,
+        S: Scheduler,
+    {
+        DistinctWithin::new(self, key_selector, window, scheduler)
+    }
+}
+
+#[cfg(feature = "tokio-scheduler")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        scheduler::tokio_scheduler::TokioScheduler, utils::checking_observer::CheckingObserver,
+    };
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    async fn test_suppressed_within_window() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.distinct_within(Duration::from_millis(10), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+    }
+
+    #[tokio::test]
+    async fn test_evicted_after_window() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            let observer = Arc::new(observer);
+            observer.notify_if_unterminated(Event::Next(1));
+            let observer_cloned = observer.clone();
+            tokio::spawn(async move {
+                sleep(Duration::from_millis(5)).await;
+                observer_cloned.notify_if_unterminated(Event::Next(1));
+            });
+            let observer_cloned = observer.clone();
+            tokio::spawn(async move {
+                sleep(Duration::from_millis(20)).await;
+                observer_cloned.notify_if_unterminated(Event::Next(1));
+            });
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.distinct_within(Duration::from_millis(10), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[1]));
+        sleep(Duration::from_millis(20)).await;
+        assert!(checker.is_values_matched(&[1, 1]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_by_key() {
+        let observable = Create::new(|observer: Box<dyn Observer<(i32, &'static str), String>>| {
+            observer.notify_if_unterminated(Event::Next((1, "a")));
+            observer.notify_if_unterminated(Event::Next((1, "b")));
+            observer.notify_if_unterminated(Event::Next((2, "c")));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.distinct_within_by_key(
+            |(id, _)| *id,
+            Duration::from_millis(10),
+            TokioScheduler::new(),
+        );
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[(1, "a"), (2, "c")]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribed() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable =
+            observable.distinct_within(Duration::from_millis(10), TokioScheduler::new());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_unsubscribed());
+    }
+}
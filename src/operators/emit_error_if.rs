@@ -0,0 +1,243 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that forwards the values of the source observable until the validator returns an error for a value, then it terminates with that error and unsubscribes the source.
+pub struct EmitErrorIf<T, O, F> {
+    source: O,
+    validator: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> EmitErrorIf<T, O, F> {
+    pub fn new(source: O, validator: F) -> EmitErrorIf<T, O, F> {
+        EmitErrorIf {
+            source,
+            validator: Arc::new(validator),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for EmitErrorIf<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        EmitErrorIf {
+            source: self.source.clone(),
+            validator: self.validator.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct EmitErrorIfState {
+    stopped: bool,
+    source_subscription: Option<Subscription>,
+}
+
+impl EmitErrorIfState {
+    /// Stop and take the source subscription, so it can be dropped outside the lock.
+    fn stop(&mut self) -> Option<Subscription> {
+        self.stopped = true;
+        self.source_subscription.take()
+    }
+}
+
+impl<T, E, O, F> Observable<T, E> for EmitErrorIf<T, O, F>
+where
+    T: Sync + Send + 'static,
+    F: Fn(&T) -> Option<E> + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(EmitErrorIfState {
+            stopped: false,
+            source_subscription: None,
+        }));
+        let validator = self.validator.clone();
+        let observer_cloned = observer.clone();
+        let state_cloned = state.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => match validator(&value) {
+                Some(error) => {
+                    let subscription = state_cloned.lock().unwrap().stop();
+                    observer_cloned
+                        .notify_if_unterminated(Event::Terminated(Terminated::Error(error)));
+                    drop(subscription);
+                }
+                None => observer_cloned.notify_if_unterminated(Event::Next(value)),
+            },
+            Event::Terminated(_) => observer_cloned.notify_if_unterminated(event),
+        });
+        let subscription = self.source.subscribe(source_observer);
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.stopped {
+            drop(state_guard);
+            drop(subscription);
+        } else {
+            state_guard.source_subscription = Some(subscription);
+            drop(state_guard);
+        }
+        Subscription::new(observer, move || {
+            let subscription = state.lock().unwrap().stop();
+            drop(subscription);
+        })
+    }
+}
+
+/// Make the `Observable` validatable.
+pub trait EmitErrorIfObservable<T, E> {
+    /**
+    Terminates with an error when the validator returns `Some(error)` for a value. The invalid value is not emitted.
+
+    # Example
+    ```rust
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::observer::event::{Event, Terminated};
+    use rx_rust::observer::Observer;
+    use rx_rust::operators::create::Create;
+    use rx_rust::operators::emit_error_if::EmitErrorIfObservable;
+    use rx_rust::subscription::Subscription;
+    let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+        observer.notify_if_unterminated(Event::Next(1));
+        observer.notify_if_unterminated(Event::Next(-1));
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    });
+    let observable = observable.emit_error_if(|value| (*value < 0).then(|| format!("negative: {}", value)));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn emit_error_if(
+        self,
+        validator: impl Fn(&T) -> Option<E> + Sync + Send + 'static,
+    ) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> EmitErrorIfObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+{
+    fn emit_error_if(
+        self,
+        validator: impl Fn(&T) -> Option<E> + Sync + Send + 'static,
+    ) -> impl Observable<T, E> {
+        EmitErrorIf::new(self, validator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source() -> impl Observable<i32, String> {
+        Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Next(-3));
+            observer.notify_if_unterminated(Event::Next(4));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_error() {
+        let observable =
+            source().emit_error_if(|value| (*value < 0).then(|| format!("negative: {}", value)));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error("negative: -3".to_owned()));
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = source().emit_error_if(|value| (*value > 10).then(|| "big".to_owned()));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, -3, 4]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_invalid_before_source_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .emit_error_if(|_| Some("invalid".to_owned()));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("invalid".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe_source_on_invalid() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let observable =
+            observable.emit_error_if(|value| (*value < 0).then(|| "negative".to_owned()));
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        assert_eq!(emitter.observer_count(), 1);
+        emitter.emit(-1);
+        assert_eq!(emitter.observer_count(), 0);
+        emitter.emit(2);
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("negative".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let observable = observable.emit_error_if(|_| None);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        subscription.unsubscribe();
+        assert_eq!(emitter.observer_count(), 0);
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_unsubscribed());
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable =
+            source().emit_error_if(|value| (*value < 0).then(|| format!("negative: {}", value)));
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error("negative: -3".to_owned()));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error("negative: -3".to_owned()));
+    }
+}
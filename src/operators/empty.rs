@@ -0,0 +1,69 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::marker::PhantomData;
+
+/**
+This is an observable that emits no values and completes immediately.
+
+# Example
+```rust
+use rx_rust::operators::empty::Empty;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = Empty::<i32, String>::new();
+observable.subscribe_on_event(|event| println!("{:?}", event));
+```
+*/
+pub struct Empty<T, E> {
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T, E> Empty<T, E> {
+    pub fn new() -> Empty<T, E> {
+        Empty {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Default for Empty<T, E> {
+    fn default() -> Self {
+        Empty::new()
+    }
+}
+
+impl<T, E> Clone for Empty<T, E> {
+    fn clone(&self) -> Self {
+        Empty::new()
+    }
+}
+
+impl<T, E> Observable<T, E> for Empty<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+
+    #[test]
+    fn test_completed() {
+        let checker = CheckingObserver::<i32, String>::new();
+        Empty::new().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+}
@@ -0,0 +1,127 @@
+use super::flat_map::FlatMap;
+use crate::observable::Observable;
+
+/// Make the `Observable` exhaust-mappable.
+pub trait ExhaustMapObservable<T, E> {
+    /**
+    Maps a value of the source observable to an inner observable, and ignores the source values arriving while that inner observable is active. The next value is only mapped after the current inner observable completes.
+    It completes when the source and the current inner observable have completed, and errors as soon as any of them errors.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::exhaust_map::ExhaustMapObservable;
+    use rx_rust::operators::range::Range;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.exhaust_map(|value| Range::new(0, value));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn exhaust_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static;
+}
+
+impl<O, T, E> ExhaustMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn exhaust_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static,
+    {
+        FlatMap::new(self, f).max_concurrent(1).drop_when_full()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::{
+            event::{Event, Terminated},
+            Observer,
+        },
+        operators::{create::Create, delay::DelayableObservable, from_iter::FromIter, just::Just},
+        scheduler::{queue_scheduler::QueueScheduler, Scheduler},
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::{convert::Infallible, sync::Arc, time::Duration};
+
+    #[test]
+    fn test_synchronous_inners() {
+        let checker = CheckingObserver::new();
+        FromIter::new([1, 2, 3])
+            .exhaust_map(|value| FromIter::new([value * 10, value * 10 + 1]))
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10, 11, 20, 21, 30, 31]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_ignores_while_active() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        // Emits 0, 1, 2, 3 at 0ms, 10ms, 20ms and 30ms, then completes at 40ms.
+        let source = Create::new(move |observer: Box<dyn Observer<u64, Infallible>>| {
+            let observer = Arc::new(observer);
+            let disposals: Vec<_> = (0..5)
+                .map(|index| {
+                    let observer = observer.clone();
+                    scheduler_cloned.schedule(
+                        move || {
+                            let event = if index < 4 {
+                                Event::Next(index)
+                            } else {
+                                Event::Terminated(Terminated::Completed)
+                            };
+                            observer.notify_if_unterminated(event)
+                        },
+                        Some(Duration::from_millis(index * 10)),
+                    )
+                })
+                .collect();
+            Subscription::new(observer, move || drop(disposals))
+        });
+        let scheduler_cloned = scheduler.clone();
+        let observable = source.exhaust_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(15), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[0, 2]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_waits_for_active_inner() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2]).exhaust_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+}
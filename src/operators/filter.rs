@@ -0,0 +1,146 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::sync::Arc;
+
+/// This is an observable that forwards only the values of the source observable matching a predicate function.
+pub struct Filter<O, F> {
+    source: O,
+    predicate: Arc<F>,
+}
+
+impl<O, F> Filter<O, F> {
+    pub fn new(source: O, predicate: F) -> Filter<O, F> {
+        Filter {
+            source,
+            predicate: Arc::new(predicate),
+        }
+    }
+}
+
+impl<O, F> Clone for Filter<O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        Filter {
+            source: self.source.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<T, E, O, F> Observable<T, E> for Filter<O, F>
+where
+    F: Fn(&T) -> bool + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let predicate = self.predicate.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            if let Event::Next(value) = &event {
+                if !predicate(value) {
+                    return;
+                }
+            }
+            observer.notify_if_unterminated(event)
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` filterable.
+pub trait FilterableObservable<T, E> {
+    /**
+    Forwards only the values of the source observable matching a predicate function.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::filter::FilterableObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=6);
+    let observable = observable.filter(|value| value % 2 == 0);
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn filter(self, f: impl Fn(&T) -> bool + Sync + Send + 'static) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> FilterableObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn filter(self, f: impl Fn(&T) -> bool + Sync + Send + 'static) -> impl Observable<T, E> {
+        Filter::new(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        operators::{create::Create, from_iter::FromIter},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_completed() {
+        let observable = FromIter::new(1..=6).filter(|value| value % 2 == 0);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[2, 4, 6]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            observer.notify_if_unterminated(Event::Next(444));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.filter(|value| *value > 400);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[444]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            observer.notify_if_unterminated(Event::Next(444));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.filter(|value| *value < 400);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = FromIter::new(1..=6).filter(|value| value % 3 == 0);
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[3, 6]));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[3, 6]));
+        assert!(checker.is_completed());
+    }
+}
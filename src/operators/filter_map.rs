@@ -0,0 +1,162 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that maps the values of the source observable using a function, forwarding the `Some` results and dropping the `None` ones.
+pub struct FilterMap<T, O, F> {
+    source: O,
+    f: Arc<Mutex<F>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> FilterMap<T, O, F> {
+    pub fn new(source: O, f: F) -> FilterMap<T, O, F> {
+        FilterMap {
+            source,
+            f: Arc::new(Mutex::new(f)),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for FilterMap<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        FilterMap {
+            source: self.source.clone(),
+            f: self.f.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, O, F, T2> Observable<T2, E> for FilterMap<T, O, F>
+where
+    T: Sync + Send + 'static,
+    F: FnMut(T) -> Option<T2> + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T2, E>) -> Subscription {
+        let f = self.f.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let value = (f.lock().unwrap())(value);
+                if let Some(value) = value {
+                    observer.notify_if_unterminated(Event::Next(value));
+                }
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` filter-mappable.
+pub trait FilterMapObservable<T, E> {
+    /**
+    Maps the values of the source observable using a function, forwarding the `Some` results and dropping the `None` ones. It is the same as `map` followed by `filter`, in one pass.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::filter_map::FilterMapObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(vec!["1", "two", "3"]);
+    let observable = observable.filter_map(|value| value.parse::<i32>().ok());
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn filter_map<T2>(
+        self,
+        f: impl FnMut(T) -> Option<T2> + Send + 'static,
+    ) -> impl Observable<T2, E>;
+}
+
+impl<O, T, E> FilterMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+{
+    fn filter_map<T2>(
+        self,
+        f: impl FnMut(T) -> Option<T2> + Send + 'static,
+    ) -> impl Observable<T2, E> {
+        FilterMap::new(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        operators::{create::Create, from_iter::FromIter},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_completed() {
+        let observable =
+            FromIter::new(vec!["1", "two", "3"]).filter_map(|value| value.parse::<i32>().ok());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_stateful() {
+        let mut previous = None;
+        let observable = FromIter::new(vec![1, 1, 2, 2, 1]).filter_map(move |value| {
+            let changed = previous != Some(value);
+            previous = Some(value);
+            changed.then_some(value)
+        });
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 1]));
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            observer.notify_if_unterminated(Event::Next(444));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.filter_map(|value| (value > 400).then(|| value.to_string()));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&["444".to_owned()]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.filter_map(Some);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+    }
+}
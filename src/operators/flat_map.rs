@@ -0,0 +1,444 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that maps each value of the source observable to an inner observable, and merges the values of all the inner observables. It completes when the source and every inner observable have completed, and errors as soon as any of them errors.
+pub struct FlatMap<T, O, F> {
+    source: O,
+    mapper: Arc<F>,
+    max_concurrent: Option<usize>,
+    drop_when_full: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> FlatMap<T, O, F> {
+    pub fn new(source: O, mapper: F) -> FlatMap<T, O, F> {
+        FlatMap {
+            source,
+            mapper: Arc::new(mapper),
+            max_concurrent: None,
+            drop_when_full: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Subscribe to at most `count` inner observables at a time, queueing the source values until one of them completes.
+    pub fn max_concurrent(mut self, count: usize) -> FlatMap<T, O, F> {
+        assert!(count > 0, "the max_concurrent of flat_map must be positive");
+        self.max_concurrent = Some(count);
+        self
+    }
+
+    /// Drop the source values arriving while `max_concurrent` inner observables are active, instead of queueing them.
+    pub(crate) fn drop_when_full(mut self) -> FlatMap<T, O, F> {
+        self.drop_when_full = true;
+        self
+    }
+}
+
+impl<T, O, F> Clone for FlatMap<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        FlatMap {
+            source: self.source.clone(),
+            mapper: self.mapper.clone(),
+            max_concurrent: self.max_concurrent,
+            drop_when_full: self.drop_when_full,
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct FlatMapState<T> {
+    stopped: bool,
+    draining: bool,
+    source_completed: bool,
+    /// The source values waiting for an inner observable to complete.
+    pending: VecDeque<T>,
+    next_id: u64,
+    /// The active inner subscriptions, `None` while the inner observable is being subscribed.
+    inners: BTreeMap<u64, Option<Subscription>>,
+    source_subscription: Option<Subscription>,
+}
+
+impl<T> FlatMapState<T> {
+    /// Stop and take every subscription, so they can be dropped outside the lock.
+    fn stop(&mut self) -> Vec<Subscription> {
+        self.stopped = true;
+        self.pending.clear();
+        let mut subscriptions: Vec<Subscription> = std::mem::take(&mut self.inners)
+            .into_values()
+            .flatten()
+            .collect();
+        subscriptions.extend(self.source_subscription.take());
+        subscriptions
+    }
+}
+
+struct Merging<T, T2, E, F, OR> {
+    mapper: Arc<F>,
+    max_concurrent: Option<usize>,
+    drop_when_full: bool,
+    observer: Arc<OR>,
+    state: Mutex<FlatMapState<T>>,
+    _marker: PhantomData<fn() -> (T2, E)>,
+}
+
+impl<T, T2, E, F, O2, OR> Merging<T, T2, E, F, OR>
+where
+    T: Sync + Send + 'static,
+    T2: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+    F: Fn(T) -> O2 + Sync + Send + 'static,
+    O2: Observable<T2, E>,
+    OR: Observer<T2, E>,
+{
+    fn on_next(self: &Arc<Self>, value: T) {
+        let mut state = self.state.lock().unwrap();
+        if state.stopped {
+            return;
+        }
+        if self.drop_when_full
+            && self
+                .max_concurrent
+                .is_some_and(|count| state.inners.len() + state.pending.len() >= count)
+        {
+            return;
+        }
+        state.pending.push_back(value);
+        drop(state);
+        self.drain();
+    }
+
+    /// Subscribe to the pending values while there is room, and complete once everything has completed.
+    /// It runs in a loop, so the inner observables completing synchronously don't grow the stack.
+    fn drain(self: &Arc<Self>) {
+        let mut state = self.state.lock().unwrap();
+        if state.draining {
+            // The loop on the stack picks up the change once the current inner observable is subscribed.
+            return;
+        }
+        state.draining = true;
+        loop {
+            if state.stopped {
+                state.draining = false;
+                return;
+            }
+            let has_room = self
+                .max_concurrent
+                .is_none_or(|count| state.inners.len() < count);
+            if has_room {
+                if let Some(value) = state.pending.pop_front() {
+                    let id = state.next_id;
+                    state.next_id += 1;
+                    state.inners.insert(id, None);
+                    drop(state);
+                    self.subscribe_inner(id, value);
+                    state = self.state.lock().unwrap();
+                    continue;
+                }
+            }
+            state.draining = false;
+            let finished = state.source_completed && state.inners.is_empty();
+            drop(state);
+            if finished {
+                self.finish(Event::Terminated(Terminated::Completed));
+            }
+            return;
+        }
+    }
+
+    fn subscribe_inner(self: &Arc<Self>, id: u64, value: T) {
+        let inner = (self.mapper)(value);
+        let merging = self.clone();
+        let inner_observer = AnonymousObserver::new(move |event: Event<T2, E>| match event {
+            Event::Next(_) => merging.observer.notify_if_unterminated(event),
+            Event::Terminated(Terminated::Completed) => merging.on_inner_completed(id),
+            Event::Terminated(Terminated::Error(_)) => merging.finish(event),
+            Event::Terminated(Terminated::Unsubscribed) => {}
+        });
+        let subscription = inner.subscribe(inner_observer);
+        let mut state = self.state.lock().unwrap();
+        match state.inners.get_mut(&id) {
+            Some(slot) => *slot = Some(subscription),
+            None => {
+                // The inner observable has already completed, or everything has stopped.
+                drop(state);
+                drop(subscription);
+            }
+        }
+    }
+
+    fn on_inner_completed(self: &Arc<Self>, id: u64) {
+        let subscription = self.state.lock().unwrap().inners.remove(&id).flatten();
+        drop(subscription);
+        self.drain();
+    }
+
+    fn on_source_completed(self: &Arc<Self>) {
+        self.state.lock().unwrap().source_completed = true;
+        self.drain();
+    }
+
+    fn finish(&self, event: Event<T2, E>) {
+        let subscriptions = self.state.lock().unwrap().stop();
+        self.observer.notify_if_unterminated(event);
+        drop(subscriptions);
+    }
+}
+
+impl<T, E, O, F, T2, O2> Observable<T2, E> for FlatMap<T, O, F>
+where
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+    T2: Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(T) -> O2 + Sync + Send + 'static,
+    O2: Observable<T2, E>,
+{
+    fn subscribe(self, observer: impl Observer<T2, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let merging = Arc::new(Merging {
+            mapper: self.mapper,
+            max_concurrent: self.max_concurrent,
+            drop_when_full: self.drop_when_full,
+            observer: observer.clone(),
+            state: Mutex::new(FlatMapState {
+                stopped: false,
+                draining: false,
+                source_completed: false,
+                pending: VecDeque::new(),
+                next_id: 0,
+                inners: BTreeMap::new(),
+                source_subscription: None,
+            }),
+            _marker: PhantomData,
+        });
+        let merging_cloned = merging.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => merging_cloned.on_next(value),
+            Event::Terminated(Terminated::Completed) => merging_cloned.on_source_completed(),
+            Event::Terminated(Terminated::Error(error)) => {
+                merging_cloned.finish(Event::Terminated(Terminated::Error(error)))
+            }
+            Event::Terminated(Terminated::Unsubscribed) => {}
+        });
+        let subscription = self.source.subscribe(source_observer);
+        {
+            let mut state = merging.state.lock().unwrap();
+            if state.stopped {
+                drop(state);
+                drop(subscription);
+            } else {
+                state.source_subscription = Some(subscription);
+            }
+        }
+        Subscription::new(observer, move || {
+            let subscriptions = merging.state.lock().unwrap().stop();
+            drop(subscriptions);
+        })
+    }
+}
+
+/// Make the `Observable` flat-mappable.
+pub trait FlatMapObservable<T, E> {
+    /**
+    Maps each value of the source observable to an inner observable, and merges the values of all the inner observables as they arrive. It completes when the source and every inner observable have completed, and errors as soon as any of them errors.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::flat_map::FlatMapObservable;
+    use rx_rust::operators::range::Range;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.flat_map(|value| Range::new(0, value));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn flat_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static;
+
+    /// The same as `flat_map`.
+    fn merge_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static;
+}
+
+impl<O, T, E> FlatMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn flat_map<T2, O2>(self, f: impl Fn(T) -> O2 + Sync + Send + 'static) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static,
+    {
+        FlatMap::new(self, f)
+    }
+
+    fn merge_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static,
+    {
+        FlatMap::new(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, delay::DelayableObservable, from_iter::FromIter, just::Just},
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::time::Duration;
+
+    fn source() -> impl Observable<i32, String> {
+        Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_synchronous_inners() {
+        let observable = source().flat_map(|value| {
+            Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+                observer.notify_if_unterminated(Event::Next(value * 10));
+                observer.notify_if_unterminated(Event::Next(value * 10 + 1));
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                Subscription::new_non_disposal_action(observer)
+            })
+        });
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10, 11, 20, 21]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_merged() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2]).flat_map(move |value| {
+            Just::new(value).delay(
+                Duration::from_millis(30 - value as u64 * 10),
+                scheduler_cloned.clone(),
+            )
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[2, 1]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_waits_for_inners() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2]).flat_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        // The value and the completion of the first inner.
+        scheduler.run_one();
+        scheduler.run_one();
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_max_concurrent() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FlatMap::new(FromIter::new([1, 2, 3]), move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        })
+        .max_concurrent(2);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        // The value and the completion of two inners.
+        assert_eq!(scheduler.pending(), 4);
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(20));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_inner_error() {
+        let observable = source().flat_map(|value| {
+            Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+                observer.notify_if_unterminated(Event::Next(value));
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(format!(
+                    "error {}",
+                    value
+                ))));
+                Subscription::new_non_disposal_action(observer)
+            })
+        });
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("error 1".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2]).flat_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[]));
+    }
+}
@@ -0,0 +1,71 @@
+use super::flat_map::FlatMap;
+use crate::observable::Observable;
+
+/// Make the `Observable` of observables flattenable.
+pub trait FlattenObservable<T, E, O2> {
+    /**
+    Subscribes to each inner observable emitted by the source observable, and merges their values as they arrive. It completes when the source and every inner observable have completed, and errors as soon as any of them errors. It is the same as `flat_map` with the identity function.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::flatten::FlattenObservable;
+    use rx_rust::operators::range::Range;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new([Range::new(0, 2), Range::new(10, 2)]);
+    let observable = observable.flatten();
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn flatten(self) -> impl Observable<T, E>;
+}
+
+impl<O, T, E, O2> FlattenObservable<T, E, O2> for O
+where
+    O: Observable<O2, E>,
+    O2: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn flatten(self) -> impl Observable<T, E> {
+        FlatMap::new(self, |inner: O2| inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{delay::DelayableObservable, from_iter::FromIter, just::Just},
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_synchronous_inners() {
+        let checker = CheckingObserver::new();
+        FromIter::new([FromIter::new(vec![1, 2]), FromIter::new(vec![3])])
+            .flatten()
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_merged() {
+        let scheduler = QueueScheduler::new();
+        let inners = [30, 10, 20].map(|millis| {
+            Just::new(millis).delay(Duration::from_millis(millis), scheduler.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = FromIter::new(inners).flatten().subscribe(checker.clone());
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[10, 20, 30]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+}
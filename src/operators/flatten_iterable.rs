@@ -0,0 +1,181 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::marker::PhantomData;
+
+/// This is an observable that emits the items of each iterable value of the source observable, in order.
+pub struct FlattenIterable<I, O> {
+    source: O,
+    _marker: PhantomData<I>,
+}
+
+impl<I, O> FlattenIterable<I, O> {
+    pub fn new(source: O) -> FlattenIterable<I, O> {
+        FlattenIterable {
+            source,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O> Clone for FlattenIterable<I, O>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenIterable {
+            source: self.source.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, E, O> Observable<I::Item, E> for FlattenIterable<I, O>
+where
+    O: Observable<I, E>,
+    I: IntoIterator + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<I::Item, E>) -> Subscription {
+        let observer = AnonymousObserver::new(move |event: Event<I, E>| match event {
+            Event::Next(values) => {
+                for value in values {
+                    if observer.terminated() {
+                        break;
+                    }
+                    observer.notify_if_unterminated(Event::Next(value));
+                }
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` of iterable values flattenable.
+pub trait FlattenIterableObservable<I, E>
+where
+    I: IntoIterator + Sync + Send + 'static,
+{
+    /**
+    Emits the items of each iterable value synchronously, without creating an inner observable per value. It's the inverse of buffering.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::flatten_iterable::FlattenIterableObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new([1, 2, 3]);
+    let observable = observable.flatten_iterable();
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn flatten_iterable(self) -> impl Observable<I::Item, E>;
+}
+
+impl<O, I, E> FlattenIterableObservable<I, E> for O
+where
+    O: Observable<I, E>,
+    I: IntoIterator + Sync + Send + 'static,
+{
+    fn flatten_iterable(self) -> impl Observable<I::Item, E> {
+        FlattenIterable::new(self)
+    }
+}
+
+/// Make the `Observable` of `Vec` values flattenable.
+pub trait FlattenVecObservable<T, E>
+where
+    T: Sync + Send + 'static,
+{
+    /**
+    Emits the values of each `Vec` synchronously. It's `flatten_iterable` for `Vec` values.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::flatten_iterable::FlattenVecObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(vec![1, 2, 3]);
+    let observable = observable.flatten_vec();
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn flatten_vec(self) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> FlattenVecObservable<T, E> for O
+where
+    O: Observable<Vec<T>, E>,
+    T: Sync + Send + 'static,
+{
+    fn flatten_vec(self) -> impl Observable<T, E> {
+        FlattenIterable::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::collections::BTreeSet;
+
+    fn source(completed: bool) -> impl Observable<Vec<i32>, String> {
+        Create::new(move |observer: Box<dyn Observer<Vec<i32>, String>>| {
+            observer.notify_if_unterminated(Event::Next(vec![1, 2]));
+            observer.notify_if_unterminated(Event::Next(vec![]));
+            observer.notify_if_unterminated(Event::Next(vec![3]));
+            if completed {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_flatten_vec() {
+        let observable = source(true).flatten_vec();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = source(false).flatten_vec();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_flatten_iterable() {
+        let observable = Create::new(|observer: Box<dyn Observer<BTreeSet<i32>, String>>| {
+            observer.notify_if_unterminated(Event::Next(BTreeSet::from([3, 1, 2])));
+            observer.notify_if_unterminated(Event::Next(BTreeSet::from([4])));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .flatten_iterable();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3, 4]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+}
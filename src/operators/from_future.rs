@@ -0,0 +1,173 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{convert::Infallible, future::Future, sync::Arc};
+
+/**
+This is an observable that calls the factory for each subscription, spawns the returned future on the current Tokio runtime, and emits its output then completes. The task is aborted when the subscription is unsubscribed or dropped.
+It must be subscribed within a Tokio runtime.
+
+# Example
+```rust
+use rx_rust::operators::from_future::FromFuture;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+#[tokio::main]
+async fn main() {
+    let observable = FromFuture::new(|| async { 333 });
+    let subscription = observable.subscribe_on_next(|value| println!("{}", value));
+}
+```
+*/
+pub struct FromFuture<F> {
+    factory: Arc<F>,
+}
+
+impl<F> FromFuture<F> {
+    pub fn new(factory: F) -> FromFuture<F> {
+        FromFuture {
+            factory: Arc::new(factory),
+        }
+    }
+}
+
+impl<F> Clone for FromFuture<F> {
+    fn clone(&self) -> Self {
+        FromFuture {
+            factory: self.factory.clone(),
+        }
+    }
+}
+
+impl<T, F, Fut> Observable<T, Infallible> for FromFuture<F>
+where
+    F: Fn() -> Fut + Sync + Send + 'static,
+    Fut: Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let observer_cloned = observer.clone();
+        let future = (self.factory)();
+        let handle = tokio::spawn(async move {
+            let value = future.await;
+            observer_cloned.notify_if_unterminated(Event::Next(value));
+            observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        });
+        Subscription::new(observer, move || handle.abort())
+    }
+}
+
+/**
+This is an observable like `FromFuture` for a future of `Result`: it emits the `Ok` value then completes, or terminates with the `Err` error.
+
+# Example
+```rust
+use rx_rust::operators::from_future::FromTryFuture;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+#[tokio::main]
+async fn main() {
+    let observable = FromTryFuture::new(|| async { "333".parse::<i32>() });
+    let subscription = observable.subscribe_on_event(|event| println!("{:?}", event));
+}
+```
+*/
+pub struct FromTryFuture<F> {
+    factory: Arc<F>,
+}
+
+impl<F> FromTryFuture<F> {
+    pub fn new(factory: F) -> FromTryFuture<F> {
+        FromTryFuture {
+            factory: Arc::new(factory),
+        }
+    }
+}
+
+impl<F> Clone for FromTryFuture<F> {
+    fn clone(&self) -> Self {
+        FromTryFuture {
+            factory: self.factory.clone(),
+        }
+    }
+}
+
+impl<T, E, F, Fut> Observable<T, E> for FromTryFuture<F>
+where
+    F: Fn() -> Fut + Sync + Send + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let observer_cloned = observer.clone();
+        let future = (self.factory)();
+        let handle = tokio::spawn(async move {
+            match future.await {
+                Ok(value) => {
+                    observer_cloned.notify_if_unterminated(Event::Next(value));
+                    observer_cloned
+                        .notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                }
+                Err(error) => observer_cloned
+                    .notify_if_unterminated(Event::Terminated(Terminated::Error(error))),
+            }
+        });
+        Subscription::new(observer, move || handle.abort())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_completed() {
+        let checker = CheckingObserver::new();
+        let subscription = FromFuture::new(|| async { 333 }).subscribe(checker.clone());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe() {
+        let checker = CheckingObserver::new();
+        let subscription = FromFuture::new(|| async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            333
+        })
+        .subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(checker.is_values_matched(&[]));
+    }
+
+    #[tokio::test]
+    async fn test_try_future() {
+        let checker = CheckingObserver::new();
+        let subscription =
+            FromTryFuture::new(|| async { Ok::<_, String>(333) }).subscribe(checker.clone());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+
+        let checker = CheckingObserver::<i32, String>::new();
+        let subscription =
+            FromTryFuture::new(|| async { Err("error".to_owned()) }).subscribe(checker.clone());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::convert::Infallible;
+
+/**
+This is an observable that emits every item of the iterable, then completes. The iterable is cloned for each subscription.
+
+# Example
+```rust
+use rx_rust::operators::from_iter::FromIter;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = FromIter::new(vec![1, 2, 3]);
+observable.subscribe_on_next(|value| println!("{}", value));
+```
+*/
+#[derive(Clone)]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+impl<I> FromIter<I> {
+    pub fn new(iter: I) -> FromIter<I> {
+        FromIter { iter }
+    }
+}
+
+impl<I> Observable<I::Item, Infallible> for FromIter<I>
+where
+    I: IntoIterator + Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<I::Item, Infallible>) -> Subscription {
+        for value in self.iter {
+            if observer.terminated() {
+                break;
+            }
+            observer.notify_if_unterminated(Event::Next(value));
+        }
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+/// Make the iterable convertible into an `Observable`.
+pub trait IntoObservable<T> {
+    /**
+    Converts the iterable into an observable that emits its items, then completes.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::IntoObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = [1, 2, 3].into_observable();
+    observable.subscribe_on_next(|value| println!("{}", value));
+    ```
+     */
+    fn into_observable(self) -> impl Observable<T, Infallible>;
+}
+
+impl<I> IntoObservable<I::Item> for I
+where
+    I: IntoIterator + Clone + Sync + Send + 'static,
+{
+    fn into_observable(self) -> impl Observable<I::Item, Infallible> {
+        FromIter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::map::MappableObservable, utils::checking_observer::CheckingObserver};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_completed() {
+        let checker = CheckingObserver::new();
+        FromIter::new(vec![1, 2, 3]).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 3]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_into_observable() {
+        let checker = CheckingObserver::new();
+        BTreeSet::from([3, 1, 2])
+            .into_observable()
+            .map(|value| value * 10)
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10, 20, 30]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = (0..2).into_observable();
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1]));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1]));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{mpsc::Receiver, Arc, Mutex},
+    thread,
+};
+
+/**
+This is an observable that drains a standard library channel on a background thread, emitting each message, and completes when all the senders are dropped.
+The receiver is taken by the first subscription; later subscriptions complete immediately. After unsubscribing, the thread stops once the next message arrives or the senders are dropped.
+
+# Example
+```rust
+use rx_rust::operators::from_receiver::FromReceiver;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use std::sync::mpsc::channel;
+let (sender, receiver) = channel();
+let observable = FromReceiver::new(receiver);
+let subscription = observable.subscribe_on_next(|message| println!("{}", message));
+sender.send(333).unwrap();
+drop(sender);
+```
+*/
+pub struct FromReceiver<T> {
+    receiver: Arc<Mutex<Option<Receiver<T>>>>,
+}
+
+impl<T> FromReceiver<T> {
+    pub fn new(receiver: Receiver<T>) -> FromReceiver<T> {
+        FromReceiver {
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+}
+
+impl<T> Clone for FromReceiver<T> {
+    fn clone(&self) -> Self {
+        FromReceiver {
+            receiver: self.receiver.clone(),
+        }
+    }
+}
+
+impl<T> Observable<T, Infallible> for FromReceiver<T>
+where
+    T: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let receiver = self.receiver.lock().unwrap().take();
+        let receiver = match receiver {
+            Some(receiver) => receiver,
+            None => {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                return Subscription::new_non_disposal_action(observer);
+            }
+        };
+        let observer_cloned = observer.clone();
+        thread::spawn(move || {
+            for message in receiver.iter() {
+                if observer_cloned.terminated() {
+                    return;
+                }
+                observer_cloned.notify_if_unterminated(Event::Next(message));
+            }
+            observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        });
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+    use std::{sync::mpsc::channel, time::Duration};
+
+    fn wait_until(condition: impl Fn() -> bool) {
+        for _ in 0..100 {
+            if condition() {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_completed() {
+        let (sender, receiver) = channel();
+        let checker = CheckingObserver::<i32, Infallible>::new();
+        let subscription = FromReceiver::new(receiver).subscribe(checker.clone());
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        drop(sender);
+        wait_until(|| checker.is_completed());
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_second_subscription() {
+        let (sender, receiver) = channel::<i32>();
+        let observable = FromReceiver::new(receiver);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        observable.subscribe(checker2.clone());
+        assert!(checker2.is_completed());
+        sender.send(1).unwrap();
+        wait_until(|| checker1.is_values_matched(&[1]));
+        assert!(checker1.is_values_matched(&[1]));
+        _ = subscription1; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let (sender, receiver) = channel();
+        let checker = CheckingObserver::<i32, Infallible>::new();
+        let subscription = FromReceiver::new(receiver).subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        sender.send(1).unwrap();
+        thread::sleep(Duration::from_millis(20));
+        assert!(checker.is_values_matched(&[]));
+    }
+}
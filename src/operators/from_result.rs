@@ -0,0 +1,123 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::convert::Infallible;
+
+/**
+This is an observable that emits the `Ok` value then completes, or terminates with the `Err` error.
+
+# Example
+```rust
+use rx_rust::operators::from_result::FromResult;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = FromResult::new("333".parse::<i32>());
+observable.subscribe_on_event(|event| println!("{:?}", event));
+```
+*/
+#[derive(Clone)]
+pub struct FromResult<T, E> {
+    result: Result<T, E>,
+}
+
+impl<T, E> FromResult<T, E> {
+    pub fn new(result: Result<T, E>) -> FromResult<T, E> {
+        FromResult { result }
+    }
+}
+
+impl<T, E> Observable<T, E> for FromResult<T, E>
+where
+    T: Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        match self.result {
+            Ok(value) => {
+                observer.notify_if_unterminated(Event::Next(value));
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Err(error) => {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(error)));
+            }
+        }
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+/**
+This is an observable that emits the `Some` value then completes, or completes without value for `None`.
+
+# Example
+```rust
+use rx_rust::operators::from_result::FromOption;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = FromOption::new(std::env::var("HOME").ok());
+observable.subscribe_on_next(|home| println!("{}", home));
+```
+*/
+#[derive(Clone)]
+pub struct FromOption<T> {
+    option: Option<T>,
+}
+
+impl<T> FromOption<T> {
+    pub fn new(option: Option<T>) -> FromOption<T> {
+        FromOption { option }
+    }
+}
+
+impl<T> Observable<T, Infallible> for FromOption<T>
+where
+    T: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        if let Some(value) = self.option {
+            observer.notify_if_unterminated(Event::Next(value));
+        }
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+
+    #[test]
+    fn test_ok() {
+        let checker = CheckingObserver::new();
+        FromResult::<i32, String>::new(Ok(333)).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_err() {
+        let checker = CheckingObserver::<i32, String>::new();
+        FromResult::new(Err("error".to_owned())).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_some() {
+        let checker = CheckingObserver::new();
+        FromOption::new(Some(333)).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_none() {
+        let checker = CheckingObserver::<i32, Infallible>::new();
+        FromOption::new(None).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::mpsc::Receiver;
+
+/**
+This is an observable that receives the messages of a Tokio mpsc channel in a task on the current runtime, and completes when all the senders are dropped. The task is aborted and the receiver dropped when the subscription is unsubscribed or dropped.
+The receiver is taken by the first subscription; later subscriptions complete immediately. It must be subscribed within a Tokio runtime.
+
+# Example
+```rust
+use rx_rust::operators::from_tokio_receiver::FromTokioReceiver;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+#[tokio::main]
+async fn main() {
+    let (sender, receiver) = tokio::sync::mpsc::channel(16);
+    let observable = FromTokioReceiver::new(receiver);
+    let subscription = observable.subscribe_on_next(|message| println!("{}", message));
+    sender.send(333).await.unwrap();
+}
+```
+*/
+pub struct FromTokioReceiver<T> {
+    receiver: Arc<Mutex<Option<Receiver<T>>>>,
+}
+
+impl<T> FromTokioReceiver<T> {
+    pub fn new(receiver: Receiver<T>) -> FromTokioReceiver<T> {
+        FromTokioReceiver {
+            receiver: Arc::new(Mutex::new(Some(receiver))),
+        }
+    }
+}
+
+impl<T> Clone for FromTokioReceiver<T> {
+    fn clone(&self) -> Self {
+        FromTokioReceiver {
+            receiver: self.receiver.clone(),
+        }
+    }
+}
+
+impl<T> Observable<T, Infallible> for FromTokioReceiver<T>
+where
+    T: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let receiver = self.receiver.lock().unwrap().take();
+        let mut receiver = match receiver {
+            Some(receiver) => receiver,
+            None => {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                return Subscription::new_non_disposal_action(observer);
+            }
+        };
+        let observer_cloned = observer.clone();
+        let handle = tokio::spawn(async move {
+            while let Some(message) = receiver.recv().await {
+                observer_cloned.notify_if_unterminated(Event::Next(message));
+            }
+            observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        });
+        Subscription::new(observer, move || handle.abort())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+    use std::time::Duration;
+    use tokio::sync::mpsc::channel;
+
+    #[tokio::test]
+    async fn test_completed() {
+        let (sender, receiver) = channel(4);
+        let checker = CheckingObserver::<i32, Infallible>::new();
+        let subscription = FromTokioReceiver::new(receiver).subscribe(checker.clone());
+        sender.send(1).await.unwrap();
+        sender.send(2).await.unwrap();
+        drop(sender);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_drops_receiver() {
+        let (sender, receiver) = channel::<i32>(4);
+        let observable = FromTokioReceiver::new(receiver);
+        let checker = CheckingObserver::new();
+        let subscription = observable.clone().subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(sender.is_closed());
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_completed());
+    }
+}
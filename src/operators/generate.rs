@@ -0,0 +1,291 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/**
+This is an observable that runs a state machine: starting from `initial_state`, while `condition(&state)` holds, it emits `result_selector(&state)` and moves on to `iterate(state)`, then completes. Each subscription starts from a clone of `initial_state`.
+
+# Example
+```rust
+use rx_rust::operators::generate::Generate;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+// 1, 2, 4, 8, 16
+let observable = Generate::new(1, |state| *state < 20, |state| state * 2, |state| *state);
+observable.subscribe_on_next(|value| println!("{}", value));
+```
+*/
+pub struct Generate<St, C, I, R> {
+    initial_state: St,
+    condition: Arc<C>,
+    iterate: Arc<I>,
+    result_selector: Arc<R>,
+}
+
+impl<St, C, I, R> Generate<St, C, I, R> {
+    pub fn new<T>(
+        initial_state: St,
+        condition: C,
+        iterate: I,
+        result_selector: R,
+    ) -> Generate<St, C, I, R>
+    where
+        C: Fn(&St) -> bool,
+        I: Fn(St) -> St,
+        R: Fn(&St) -> T,
+    {
+        Generate {
+            initial_state,
+            condition: Arc::new(condition),
+            iterate: Arc::new(iterate),
+            result_selector: Arc::new(result_selector),
+        }
+    }
+
+    /// Emit each value `delay` after the previous one on the scheduler, instead of all at once on subscribe.
+    pub fn scheduled<S>(self, delay: Duration, scheduler: S) -> ScheduledGenerate<St, C, I, R, S> {
+        ScheduledGenerate {
+            generate: self,
+            delay,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<St, C, I, R> Clone for Generate<St, C, I, R>
+where
+    St: Clone,
+{
+    fn clone(&self) -> Self {
+        Generate {
+            initial_state: self.initial_state.clone(),
+            condition: self.condition.clone(),
+            iterate: self.iterate.clone(),
+            result_selector: self.result_selector.clone(),
+        }
+    }
+}
+
+impl<T, St, C, I, R> Observable<T, Infallible> for Generate<St, C, I, R>
+where
+    St: Clone + Sync + Send + 'static,
+    C: Fn(&St) -> bool + Sync + Send + 'static,
+    I: Fn(St) -> St + Sync + Send + 'static,
+    R: Fn(&St) -> T + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let mut state = self.initial_state;
+        while (self.condition)(&state) {
+            if observer.terminated() {
+                break;
+            }
+            observer.notify_if_unterminated(Event::Next((self.result_selector)(&state)));
+            state = (self.iterate)(state);
+        }
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+/// This is an observable like `Generate`, which emits each value `delay` after the previous one on the scheduler. The pending step is cancelled when the subscription is unsubscribed or dropped.
+pub struct ScheduledGenerate<St, C, I, R, S> {
+    generate: Generate<St, C, I, R>,
+    delay: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<St, C, I, R, S> Clone for ScheduledGenerate<St, C, I, R, S>
+where
+    St: Clone,
+{
+    fn clone(&self) -> Self {
+        ScheduledGenerate {
+            generate: self.generate.clone(),
+            delay: self.delay,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct GenerateState<St> {
+    stopped: bool,
+    step: usize,
+    state: Option<St>,
+    timer: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+}
+
+struct Generating<St, C, I, R, S, OR> {
+    generate: ScheduledGenerate<St, C, I, R, S>,
+    observer: Arc<OR>,
+    state: Mutex<GenerateState<St>>,
+}
+
+impl<T, St, C, I, R, S, OR> Generating<St, C, I, R, S, OR>
+where
+    St: Clone + Sync + Send + 'static,
+    C: Fn(&St) -> bool + Sync + Send + 'static,
+    I: Fn(St) -> St + Sync + Send + 'static,
+    R: Fn(&St) -> T + Sync + Send + 'static,
+    S: Scheduler,
+    OR: Observer<T, Infallible>,
+{
+    fn schedule(self: &Arc<Self>, step: usize) {
+        let generating = self.clone();
+        let timer = self
+            .generate
+            .scheduler
+            .schedule(move || generating.run(), Some(self.generate.delay));
+        let timer = timer.to_boxed();
+        let mut state = self.state.lock().unwrap();
+        let previous_timer = if !state.stopped && state.step <= step {
+            state.step = step;
+            state.timer.replace(timer)
+        } else {
+            // Unsubscribed, or the timer has already run and scheduled the next one.
+            Some(timer)
+        };
+        drop(state);
+        drop(previous_timer);
+    }
+
+    fn run(self: &Arc<Self>) {
+        let generate = &self.generate.generate;
+        let mut state = self.state.lock().unwrap();
+        if state.stopped {
+            return;
+        }
+        let current = state.state.take().unwrap();
+        if !(generate.condition)(&current) {
+            state.stopped = true;
+            let timer = state.timer.take();
+            drop(state);
+            self.observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            drop(timer);
+            return;
+        }
+        let value = (generate.result_selector)(&current);
+        state.state = Some((generate.iterate)(current));
+        let step = state.step + 1;
+        drop(state);
+        self.observer.notify_if_unterminated(Event::Next(value));
+        self.schedule(step);
+    }
+}
+
+impl<T, St, C, I, R, S> Observable<T, Infallible> for ScheduledGenerate<St, C, I, R, S>
+where
+    St: Clone + Sync + Send + 'static,
+    C: Fn(&St) -> bool + Sync + Send + 'static,
+    I: Fn(St) -> St + Sync + Send + 'static,
+    R: Fn(&St) -> T + Sync + Send + 'static,
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let initial_state = self.generate.initial_state.clone();
+        let generating = Arc::new(Generating {
+            generate: self,
+            observer: observer.clone(),
+            state: Mutex::new(GenerateState {
+                stopped: false,
+                step: 0,
+                state: Some(initial_state),
+                timer: None,
+            }),
+        });
+        generating.schedule(0);
+        Subscription::new(observer, move || {
+            let timer = {
+                let mut state = generating.state.lock().unwrap();
+                state.stopped = true;
+                state.timer.take()
+            };
+            drop(timer);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler, utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_completed() {
+        let observable = Generate::new(1, |state| *state < 20, |state| state * 2, |state| *state);
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 4, 8, 16]));
+        assert!(checker.is_completed());
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2, 4, 8, 16]));
+    }
+
+    #[test]
+    fn test_result_selector() {
+        let observable = Generate::new(
+            (0, 1),
+            |(index, _)| *index < 6,
+            |(index, value)| (index + 1, value * 3),
+            |(index, value)| format!("{}:{}", index, value),
+        );
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            "0:1".to_owned(),
+            "1:3".to_owned(),
+            "2:9".to_owned(),
+            "3:27".to_owned(),
+            "4:81".to_owned(),
+            "5:243".to_owned(),
+        ]));
+    }
+
+    #[test]
+    fn test_scheduled() {
+        let scheduler = QueueScheduler::new();
+        let observable = Generate::new(0, |state| *state < 3, |state| state + 1, |state| *state)
+            .scheduled(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        scheduler.run_one();
+        assert!(checker.is_values_matched(&[0]));
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[0, 1, 2]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(40));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_scheduled_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let observable = Generate::new(0, |_| true, |state| state + 1, |state| *state)
+            .scheduled(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        scheduler.run_one();
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[0, 1]));
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+type StopAction = Box<dyn FnOnce() + Sync + Send + 'static>;
+
+struct RegistryState {
+    shut_down: bool,
+    next_id: u64,
+    pipelines: BTreeMap<u64, (String, StopAction)>,
+}
+
+/// The result of `ShutdownRegistry::shutdown`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// The pipelines that stopped within the timeout.
+    pub stopped: Vec<String>,
+    /// The pipelines whose finalizers were still running when the timeout elapsed.
+    pub timed_out: Vec<String>,
+}
+
+impl ShutdownReport {
+    /// Get whether every pipeline stopped in time.
+    pub fn is_clean(&self) -> bool {
+        self.timed_out.is_empty()
+    }
+}
+
+/**
+A registry of long-lived pipelines that can be stopped together when the service shuts down. A pipeline joins the registry with `graceful_shutdown`, and leaves it when it terminates or is unsubscribed.
+
+# Example
+```rust
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::operators::graceful_shutdown::{GracefulShutdownObservable, ShutdownRegistry};
+use rx_rust::operators::just::Just;
+use std::time::Duration;
+# tokio::runtime::Runtime::new().unwrap().block_on(async {
+let registry = ShutdownRegistry::new();
+let subscription = Just::new(333)
+    .graceful_shutdown(&registry, "ingest")
+    .subscribe_on_next(|value| println!("{}", value));
+let report = registry.shutdown(Duration::from_secs(5)).await;
+assert!(report.is_clean());
+# });
+```
+*/
+#[derive(Clone)]
+pub struct ShutdownRegistry {
+    state: Arc<Mutex<RegistryState>>,
+}
+
+impl ShutdownRegistry {
+    pub fn new() -> ShutdownRegistry {
+        ShutdownRegistry {
+            state: Arc::new(Mutex::new(RegistryState {
+                shut_down: false,
+                next_id: 0,
+                pipelines: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// The names of the pipelines currently registered.
+    pub fn active(&self) -> Vec<String> {
+        let state = self.state.lock().unwrap();
+        state
+            .pipelines
+            .values()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Get whether `shutdown` has been called.
+    pub fn is_shut_down(&self) -> bool {
+        self.state.lock().unwrap().shut_down
+    }
+
+    /**
+    Completes every registered pipeline and unsubscribes it from its source, then waits up to `timeout` for the finalizers to return. The finalizers run on the blocking thread pool, so a slow one doesn't hold up the others.
+    Pipelines subscribing afterwards are completed immediately. It must be called within a Tokio runtime.
+     */
+    pub async fn shutdown(&self, timeout: Duration) -> ShutdownReport {
+        let pipelines: Vec<(String, StopAction)> = {
+            let mut state = self.state.lock().unwrap();
+            state.shut_down = true;
+            std::mem::take(&mut state.pipelines).into_values().collect()
+        };
+        let handles: Vec<_> = pipelines
+            .into_iter()
+            .map(|(name, action)| (name, tokio::task::spawn_blocking(action)))
+            .collect();
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut report = ShutdownReport::default();
+        for (name, handle) in handles {
+            match tokio::time::timeout_at(deadline, handle).await {
+                Ok(_) => report.stopped.push(name),
+                Err(_) => report.timed_out.push(name),
+            }
+        }
+        report
+    }
+
+    /// Returns `None` if the registry is already shut down.
+    fn register(&self, name: String, action: StopAction) -> Option<u64> {
+        let mut state = self.state.lock().unwrap();
+        if state.shut_down {
+            return None;
+        }
+        let id = state.next_id;
+        state.next_id += 1;
+        state.pipelines.insert(id, (name, action));
+        Some(id)
+    }
+
+    fn unregister(&self, id: u64) {
+        let pipeline = self.state.lock().unwrap().pipelines.remove(&id);
+        drop(pipeline);
+    }
+}
+
+impl Default for ShutdownRegistry {
+    fn default() -> Self {
+        ShutdownRegistry::new()
+    }
+}
+
+/// This is an observable that forwards the source observable, and is completed and unsubscribed from the source when its `ShutdownRegistry` shuts down.
+#[derive(Clone)]
+pub struct GracefulShutdown<O> {
+    source: O,
+    registry: ShutdownRegistry,
+    name: String,
+}
+
+impl<O> GracefulShutdown<O> {
+    pub fn new(source: O, registry: ShutdownRegistry, name: String) -> GracefulShutdown<O> {
+        GracefulShutdown {
+            source,
+            registry,
+            name,
+        }
+    }
+}
+
+struct GracefulShutdownState {
+    stopped: bool,
+    source_subscription: Option<Subscription>,
+}
+
+impl GracefulShutdownState {
+    /// Stop and take the source subscription, so it can be dropped outside the lock.
+    fn stop(&mut self) -> Option<Subscription> {
+        self.stopped = true;
+        self.source_subscription.take()
+    }
+}
+
+impl<T, E, O> Observable<T, E> for GracefulShutdown<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let registry = self.registry.clone();
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(GracefulShutdownState {
+            stopped: false,
+            source_subscription: None,
+        }));
+        let observer_cloned = observer.clone();
+        let state_cloned = state.clone();
+        let id = registry.register(
+            self.name,
+            Box::new(move || {
+                let subscription = state_cloned.lock().unwrap().stop();
+                observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                drop(subscription);
+            }),
+        );
+        let id = match id {
+            Some(id) => id,
+            None => {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                return Subscription::new_non_disposal_action(observer);
+            }
+        };
+        let observer_cloned = observer.clone();
+        let registry_cloned = registry.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            if let Event::Terminated(_) = event {
+                registry_cloned.unregister(id);
+            }
+            observer_cloned.notify_if_unterminated(event);
+        });
+        let subscription = self.source.subscribe(source_observer);
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.stopped || observer.terminated() {
+            drop(state_guard);
+            drop(subscription);
+        } else {
+            state_guard.source_subscription = Some(subscription);
+            drop(state_guard);
+        }
+        Subscription::new(observer, move || {
+            registry.unregister(id);
+            let subscription = state.lock().unwrap().stop();
+            drop(subscription);
+        })
+    }
+}
+
+/// Make the `Observable` stoppable by a `ShutdownRegistry`.
+pub trait GracefulShutdownObservable<T, E> {
+    /**
+    Registers each subscription in `registry` under `name`, so `registry.shutdown(timeout)` completes it and unsubscribes it from the source, like `take_until` with a shared signal.
+
+    # Example
+    ```rust
+    use rx_rust::operators::graceful_shutdown::{GracefulShutdownObservable, ShutdownRegistry};
+    use rx_rust::operators::just::Just;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let registry = ShutdownRegistry::new();
+    let observable = Just::new(333).graceful_shutdown(&registry, "ingest");
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn graceful_shutdown(
+        self,
+        registry: &ShutdownRegistry,
+        name: impl Into<String>,
+    ) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> GracefulShutdownObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn graceful_shutdown(
+        self,
+        registry: &ShutdownRegistry,
+        name: impl Into<String>,
+    ) -> impl Observable<T, E> {
+        GracefulShutdown::new(self, registry.clone(), name.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source(finalizer_delay: Duration) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            Subscription::new(observer, move || std::thread::sleep(finalizer_delay))
+        })
+    }
+
+    #[test]
+    fn test_completed_source_leaves_registry() {
+        let registry = ShutdownRegistry::new();
+        let checker = CheckingObserver::new();
+        Just::new(333)
+            .graceful_shutdown(&registry, "just")
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        assert!(registry.active().is_empty());
+    }
+
+    #[test]
+    fn test_unsubscribe_leaves_registry() {
+        let registry = ShutdownRegistry::new();
+        let checker = CheckingObserver::new();
+        let subscription = source(Duration::ZERO)
+            .graceful_shutdown(&registry, "source")
+            .subscribe(checker.clone());
+        assert_eq!(registry.active(), vec!["source".to_owned()]);
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(registry.active().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() {
+        let registry = ShutdownRegistry::new();
+        let checker1 = CheckingObserver::new();
+        let subscription1 = source(Duration::ZERO)
+            .graceful_shutdown(&registry, "fast")
+            .subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = source(Duration::from_millis(500))
+            .graceful_shutdown(&registry, "slow")
+            .subscribe(checker2.clone());
+        let report = registry.shutdown(Duration::from_millis(100)).await;
+        assert_eq!(report.stopped, vec!["fast".to_owned()]);
+        assert_eq!(report.timed_out, vec!["slow".to_owned()]);
+        assert!(!report.is_clean());
+        assert!(checker1.is_values_matched(&[1]));
+        assert!(checker1.is_completed());
+        assert!(checker2.is_completed());
+        assert!(registry.is_shut_down());
+        assert!(registry.active().is_empty());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_shutdown_while_subscribing() {
+        let registry = ShutdownRegistry::new();
+        let disposals = Arc::new(Mutex::new(0));
+        let registry_cloned = registry.clone();
+        let disposals_cloned = disposals.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            // Shut down before the source subscription is returned.
+            let registry = registry_cloned.clone();
+            std::thread::spawn(move || {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                runtime.block_on(registry.shutdown(Duration::from_secs(1)))
+            })
+            .join()
+            .unwrap();
+            let disposals = disposals_cloned.clone();
+            Subscription::new(observer, move || *disposals.lock().unwrap() += 1)
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable
+            .graceful_shutdown(&registry, "source")
+            .subscribe(checker.clone());
+        assert!(checker.is_completed());
+        assert_eq!(*disposals.lock().unwrap(), 1);
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_after_shutdown() {
+        let registry = ShutdownRegistry::new();
+        let report = registry.shutdown(Duration::from_millis(100)).await;
+        assert!(report.is_clean());
+        let checker = CheckingObserver::new();
+        source(Duration::ZERO)
+            .graceful_shutdown(&registry, "late")
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    observable::{
+        hot_observable::{HotEmitter, HotObservable},
+        Observable,
+    },
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable of the values of one group of `GroupBy`, together with their key.
+pub struct GroupedObservable<K, T, E> {
+    key: K,
+    observable: HotObservable<T, E>,
+}
+
+impl<K, T, E> GroupedObservable<K, T, E> {
+    /// The key shared by all the values of the group.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+}
+
+impl<K, T, E> Clone for GroupedObservable<K, T, E>
+where
+    K: Clone,
+{
+    fn clone(&self) -> Self {
+        GroupedObservable {
+            key: self.key.clone(),
+            observable: self.observable.clone(),
+        }
+    }
+}
+
+impl<K, T, E> Observable<T, E> for GroupedObservable<K, T, E>
+where
+    K: Clone + Sync + Send + 'static,
+    T: Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        self.observable.subscribe(observer)
+    }
+}
+
+/// This is an observable that routes the values of the source observable into groups by their key, and emits a `GroupedObservable` for each new key when its first value arrives. All the groups are terminated together with the source.
+pub struct GroupBy<T, O, F> {
+    source: O,
+    key_selector: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> GroupBy<T, O, F> {
+    pub fn new(source: O, key_selector: F) -> GroupBy<T, O, F> {
+        GroupBy {
+            source,
+            key_selector: Arc::new(key_selector),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for GroupBy<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        GroupBy {
+            source: self.source.clone(),
+            key_selector: self.key_selector.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, K, E, O, F> Observable<GroupedObservable<K, T, E>, E> for GroupBy<T, O, F>
+where
+    T: Clone + Sync + Send + 'static,
+    K: Hash + Eq + Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(&T) -> K + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<GroupedObservable<K, T, E>, E>) -> Subscription {
+        let key_selector = self.key_selector.clone();
+        let groups: Mutex<HashMap<K, HotEmitter<T, E>>> = Mutex::new(HashMap::new());
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let key = key_selector(&value);
+                let mut groups_guard = groups.lock().unwrap();
+                let (group, opened) = match groups_guard.get(&key) {
+                    Some(group) => (group.clone(), None),
+                    None => {
+                        let (group, observable) = HotObservable::new();
+                        groups_guard.insert(key.clone(), group.clone());
+                        (group, Some(GroupedObservable { key, observable }))
+                    }
+                };
+                drop(groups_guard);
+                if let Some(grouped) = opened {
+                    // Emitted before its first value, so the downstream can subscribe to it in time.
+                    observer.notify_if_unterminated(Event::Next(grouped));
+                }
+                group.emit(value);
+            }
+            Event::Terminated(terminated) => {
+                let groups = std::mem::take(&mut *groups.lock().unwrap());
+                for group in groups.into_values() {
+                    match &terminated {
+                        Terminated::Error(error) => group.error(error.clone()),
+                        _ => group.complete(),
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` groupable by key.
+pub trait GroupByObservable<T, E> {
+    /**
+    Routes the values into groups by their key, and emits a `GroupedObservable` for each new key when its first value arrives. Subscribe to the group when it is emitted to receive all its values.
+    All the groups are completed when the source completes or is unsubscribed, and receive the error when the source errors.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::group_by::GroupByObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=6);
+    let observable = observable.group_by(|value| value % 2 == 0);
+    observable.subscribe_on_next(|group| {
+        let even = *group.key();
+        group.subscribe_on_next(move |value| println!("even {}: {}", even, value));
+    });
+    ```
+     */
+    fn group_by<K>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> impl Observable<GroupedObservable<K, T, E>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        K: Hash + Eq + Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> GroupByObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn group_by<K>(
+        self,
+        key_selector: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> impl Observable<GroupedObservable<K, T, E>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        K: Hash + Eq + Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+    {
+        GroupBy::new(self, key_selector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+
+    fn source(values: Vec<i32>, end: Option<Terminated<String>>) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            if let Some(end) = end.clone() {
+                observer.notify_if_unterminated(Event::Terminated(end));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    /// The key, checker and subscription of each group.
+    type Groups = Arc<Mutex<Vec<(i32, CheckingObserver<i32, String>, Subscription)>>>;
+
+    /// Subscribe to the groups as they are emitted.
+    fn subscribe_groups(
+        observable: impl Observable<GroupedObservable<i32, i32, String>, String>,
+    ) -> (
+        CheckingObserver<GroupedObservable<i32, i32, String>, String>,
+        Groups,
+        Subscription,
+    ) {
+        let checker = CheckingObserver::new();
+        let groups = Arc::new(Mutex::new(Vec::new()));
+        let groups_cloned = groups.clone();
+        let checker_cloned = checker.clone();
+        let subscription = observable.subscribe(AnonymousObserver::new(
+            move |event: Event<GroupedObservable<i32, i32, String>, String>| {
+                if let Event::Next(group) = &event {
+                    let group_checker = CheckingObserver::new();
+                    let group_subscription = group.clone().subscribe(group_checker.clone());
+                    groups_cloned.lock().unwrap().push((
+                        *group.key(),
+                        group_checker,
+                        group_subscription,
+                    ));
+                }
+                checker_cloned.notify_if_unterminated(event);
+            },
+        ));
+        (checker, groups, subscription)
+    }
+
+    #[test]
+    fn test_completed() {
+        let (checker, groups, subscription) = subscribe_groups(
+            source(vec![1, 2, 4, 3, 6, 9], Some(Terminated::Completed)).group_by(|value| value % 3),
+        );
+        let groups = groups.lock().unwrap();
+        assert_eq!(
+            groups.iter().map(|(key, _, _)| *key).collect::<Vec<_>>(),
+            vec![1, 2, 0]
+        );
+        assert!(groups[0].1.is_values_matched(&[1, 4]));
+        assert!(groups[1].1.is_values_matched(&[2]));
+        assert!(groups[2].1.is_values_matched(&[3, 6, 9]));
+        assert!(groups.iter().all(|(_, group, _)| group.is_completed()));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let (checker, groups, subscription) = subscribe_groups(
+            source(vec![1, 2], Some(Terminated::Error("error".to_owned())))
+                .group_by(|value| *value),
+        );
+        let groups = groups.lock().unwrap();
+        assert_eq!(groups.len(), 2);
+        assert!(groups
+            .iter()
+            .all(|(_, group, _)| group.is_error("error".to_owned())));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let (checker, groups, subscription) =
+            subscribe_groups(source(vec![1, 2], None).group_by(|value| *value));
+        assert!(groups
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, group, _)| group.is_unterminated()));
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(groups
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, group, _)| group.is_completed()));
+    }
+}
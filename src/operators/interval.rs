@@ -0,0 +1,194 @@
+use super::synthetic::{uniform_jitter, Synthetic};
+use crate::{
+    observable::Observable, observer::Observer, scheduler::Scheduler, subscription::Subscription,
+};
+use std::{
+    collections::hash_map::RandomState,
+    convert::Infallible,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::Duration,
+};
+
+/**
+This is an observable that emits 0, 1, 2, ... on the scheduler, one value every `period`, starting one `period` after subscribing. It never completes; the timer is cancelled when the subscription is unsubscribed or dropped. `with_jitter` creates one whose ticks are randomized around the period.
+
+# Example
+```rust
+use rx_rust::operators::interval::Interval;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+use std::time::Duration;
+let scheduler = QueueScheduler::new();
+let observable = Interval::new(Duration::from_secs(1), scheduler.clone());
+let subscription = observable.subscribe_on_next(|tick| println!("tick {}", tick));
+scheduler.run_one();
+scheduler.run_one();
+```
+*/
+pub struct Interval<S> {
+    period: Duration,
+    jitter: f64,
+    scheduler: Arc<S>,
+}
+
+impl<S> Interval<S> {
+    pub fn new(period: Duration, scheduler: S) -> Interval<S> {
+        Interval {
+            period,
+            jitter: 0.0,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+
+    /**
+    Create an interval whose ticks are each randomized within `period` ± `period * jitter_fraction`, so that many clients polling on the same period don't fire together. Each subscription gets its own random sequence of delays.
+
+    Panics if `jitter_fraction` is not between 0 and 1.
+
+    # Example
+    ```rust
+    use rx_rust::operators::interval::Interval;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+    use std::time::Duration;
+    let scheduler = QueueScheduler::new();
+    let observable = Interval::with_jitter(Duration::from_secs(10), 0.2, scheduler.clone());
+    let subscription = observable.subscribe_on_next(|tick| println!("poll {}", tick));
+    scheduler.run_one();
+    assert!(scheduler.now() >= Duration::from_secs(8));
+    assert!(scheduler.now() <= Duration::from_secs(12));
+    ```
+     */
+    pub fn with_jitter(period: Duration, jitter_fraction: f64, scheduler: S) -> Interval<S> {
+        assert!(
+            (0.0..=1.0).contains(&jitter_fraction),
+            "the jitter fraction of with_jitter must be between 0 and 1"
+        );
+        Interval {
+            period,
+            jitter: jitter_fraction,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<S> Clone for Interval<S> {
+    fn clone(&self) -> Self {
+        Interval {
+            period: self.period,
+            jitter: self.jitter,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+impl<S> Observable<usize, Infallible> for Interval<S>
+where
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<usize, Infallible>) -> Subscription {
+        let period = self.period;
+        if self.jitter == 0.0 {
+            return Synthetic::new(move |_| period, |index| index, self.scheduler)
+                .subscribe(observer);
+        }
+        let spread = period.mul_f64(self.jitter);
+        let seed = RandomState::new().build_hasher().finish();
+        let delay = uniform_jitter(period - spread, period + spread, seed);
+        Synthetic::new(delay, |index| index, self.scheduler).subscribe(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler, utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_ticks() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription =
+            Interval::new(Duration::from_millis(10), scheduler.clone()).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        for _ in 0..3 {
+            scheduler.run_one();
+        }
+        assert_eq!(scheduler.now(), Duration::from_millis(30));
+        assert!(checker.is_values_matched(&[0, 1, 2]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_dropped() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        {
+            let subscription = Interval::new(Duration::from_millis(10), scheduler.clone())
+                .subscribe(checker.clone());
+            scheduler.run_one();
+            _ = subscription; // keep the subscription alive
+        }
+        assert!(checker.is_values_matched(&[0]));
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_jitter() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription =
+            Interval::with_jitter(Duration::from_millis(100), 0.5, scheduler.clone())
+                .subscribe(checker.clone());
+        let mut last = Duration::ZERO;
+        for _ in 0..20 {
+            scheduler.run_one();
+            let delay = scheduler.now() - last;
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(150));
+            last = scheduler.now();
+        }
+        assert!(checker.is_values_matched(&(0..20).collect::<Vec<_>>()));
+        subscription.unsubscribe();
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_zero_jitter() {
+        let scheduler = QueueScheduler::new();
+        let subscription = Interval::with_jitter(Duration::from_millis(10), 0.0, scheduler.clone())
+            .subscribe(CheckingObserver::<usize, Infallible>::new());
+        scheduler.run_one();
+        scheduler.run_one();
+        assert_eq!(scheduler.now(), Duration::from_millis(20));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    #[should_panic(expected = "the jitter fraction of with_jitter must be between 0 and 1")]
+    fn test_invalid_jitter() {
+        Interval::with_jitter(Duration::from_millis(10), 1.5, QueueScheduler::new());
+    }
+
+    #[cfg(feature = "tokio-scheduler")]
+    #[tokio::test]
+    async fn test_tokio_scheduler() {
+        use crate::{
+            observable::observable_subscribe_ext::ObservableSubscribeExt,
+            scheduler::tokio_scheduler::TokioScheduler,
+        };
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let subscription = Interval::new(Duration::from_millis(5), TokioScheduler::new())
+            .subscribe_on_next(move |tick| _ = sender.send(tick));
+        assert_eq!(receiver.recv().await, Some(0));
+        assert_eq!(receiver.recv().await, Some(1));
+        subscription.unsubscribe();
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that keeps the latest value of the source observable for each key, and emits a snapshot of the map on each value.
+pub struct KeyedLatest<T, O, F> {
+    source: O,
+    key: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> KeyedLatest<T, O, F> {
+    pub fn new(source: O, key: F) -> KeyedLatest<T, O, F> {
+        KeyedLatest {
+            source,
+            key: Arc::new(key),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for KeyedLatest<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        KeyedLatest {
+            source: self.source.clone(),
+            key: self.key.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, K, E, O, F> Observable<Arc<HashMap<K, T>>, E> for KeyedLatest<T, O, F>
+where
+    T: Clone + Sync + Send + 'static,
+    K: Eq + Hash + Clone + Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(&T) -> K + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Arc<HashMap<K, T>>, E>) -> Subscription {
+        let key = self.key.clone();
+        let latest = Mutex::new(Arc::new(HashMap::new()));
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut latest = latest.lock().unwrap();
+                // The map is only copied if a previous snapshot is still held downstream.
+                Arc::make_mut(&mut latest).insert(key(&value), value);
+                let snapshot = latest.clone();
+                drop(latest);
+                observer.notify_if_unterminated(Event::Next(snapshot));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` accumulate the latest value per key.
+pub trait KeyedLatestObservable<T, E> {
+    /**
+    Keeps the latest value for each key, and emits the map as an `Arc` snapshot on each value, e.g. for a live dashboard keyed by entity. Emitting a snapshot doesn't copy the map; the map is copied on the next value only while a previous snapshot is still held.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::keyed_latest::KeyedLatestObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(("sensor-1", 21.5));
+    let observable = observable.keyed_latest(|(sensor, _)| *sensor);
+    observable.subscribe_on_next(|snapshot| {
+        for (sensor, (_, temperature)) in snapshot.iter() {
+            println!("{}: {}", sensor, temperature);
+        }
+    });
+    ```
+     */
+    fn keyed_latest<K>(
+        self,
+        key: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> impl Observable<Arc<HashMap<K, T>>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        K: Eq + Hash + Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> KeyedLatestObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn keyed_latest<K>(
+        self,
+        key: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> impl Observable<Arc<HashMap<K, T>>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        K: Eq + Hash + Clone + Sync + Send + 'static,
+    {
+        KeyedLatest::new(self, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source() -> impl Observable<(&'static str, i32), String> {
+        Create::new(|observer: Box<dyn Observer<(&'static str, i32), String>>| {
+            observer.notify_if_unterminated(Event::Next(("a", 1)));
+            observer.notify_if_unterminated(Event::Next(("b", 2)));
+            observer.notify_if_unterminated(Event::Next(("a", 3)));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    fn snapshot(
+        entries: &[(&'static str, i32)],
+    ) -> Arc<HashMap<&'static str, (&'static str, i32)>> {
+        Arc::new(
+            entries
+                .iter()
+                .map(|(key, value)| (*key, (*key, *value)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = source().keyed_latest(|(key, _)| *key);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            snapshot(&[("a", 1)]),
+            snapshot(&[("a", 1), ("b", 2)]),
+            snapshot(&[("a", 3), ("b", 2)]),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<(&'static str, i32), String>>| {
+            observer.notify_if_unterminated(Event::Next(("a", 1)));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .keyed_latest(|(key, _)| *key);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[snapshot(&[("a", 1)])]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = source().keyed_latest(|(key, _)| *key);
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            snapshot(&[("a", 1)]),
+            snapshot(&[("a", 1), ("b", 2)]),
+            snapshot(&[("a", 3), ("b", 2)]),
+        ]));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            snapshot(&[("a", 1)]),
+            snapshot(&[("a", 1), ("b", 2)]),
+            snapshot(&[("a", 3), ("b", 2)]),
+        ]));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{marker::PhantomData, sync::Arc};
+
+/// This is an observable that maps the error of the source observable using a mapper function, and forwards the values untouched.
+pub struct MapErr<E, O, F> {
+    source: O,
+    mapper: Arc<F>,
+    _marker: PhantomData<E>,
+}
+
+impl<E, O, F> MapErr<E, O, F> {
+    pub fn new(source: O, mapper: F) -> MapErr<E, O, F> {
+        MapErr {
+            source,
+            mapper: Arc::new(mapper),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E, O, F> Clone for MapErr<E, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        MapErr {
+            source: self.source.clone(),
+            mapper: self.mapper.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, O, F, E2> Observable<T, E2> for MapErr<E, O, F>
+where
+    E: Sync + Send + 'static,
+    F: Fn(E) -> E2 + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E2>) -> Subscription {
+        let mapper = self.mapper.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            observer.notify_if_unterminated(event.map_error(|error| mapper(error)))
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the error of the `Observable` mappable.
+pub trait MapErrObservable<T, E> {
+    /**
+    Maps the error of the source observable using a mapper function, and forwards the values untouched, e.g. to combine sources with different error types.
+
+    # Example
+    ```rust
+    use rx_rust::operators::throw::Throw;
+    use rx_rust::operators::map_err::MapErrObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Throw::new(404);
+    let observable = observable.map_err(|code| format!("status {}", code));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn map_err<E2>(self, f: impl Fn(E) -> E2 + Sync + Send + 'static) -> impl Observable<T, E2>;
+}
+
+impl<O, T, E> MapErrObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    E: Sync + Send + 'static,
+{
+    fn map_err<E2>(self, f: impl Fn(E) -> E2 + Sync + Send + 'static) -> impl Observable<T, E2> {
+        MapErr::new(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, i32>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Error(404)));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.map_err(|code| format!("status {}", code));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_error("status 404".to_owned()));
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = Just::new(333).map_err(|_| "never".to_owned());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, i32>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .map_err(|code| code.to_string());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+/// An event of an observable as a plain value, emitted by `materialize`. Unlike `Event`, it has no `Unsubscribed`, which is not sent by the observable itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notification<T, E> {
+    Next(T),
+    Completed,
+    Error(E),
+}
+
+/// This is an observable that emits the values and the terminated event of the source observable as `Notification` values, then completes.
+#[derive(Clone)]
+pub struct Materialize<O> {
+    source: O,
+}
+
+impl<O> Materialize<O> {
+    pub fn new(source: O) -> Materialize<O> {
+        Materialize { source }
+    }
+}
+
+impl<T, E, O> Observable<Notification<T, E>, Infallible> for Materialize<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<Notification<T, E>, Infallible>) -> Subscription {
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            let notification = match event {
+                Event::Next(value) => Notification::Next(value),
+                Event::Terminated(Terminated::Completed) => Notification::Completed,
+                Event::Terminated(Terminated::Error(error)) => Notification::Error(error),
+                Event::Terminated(Terminated::Unsubscribed) => {
+                    observer.notify_if_unterminated(Event::Terminated(Terminated::Unsubscribed));
+                    return;
+                }
+            };
+            let terminated = !matches!(notification, Notification::Next(_));
+            observer.notify_if_unterminated(Event::Next(notification));
+            if terminated {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// A `Notification` with its provenance, emitted by `materialize_with_meta`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationWithMeta<T, E> {
+    pub notification: Notification<T, E>,
+    /// Unique in the process for each subscription to `materialize_with_meta`.
+    pub subscription_id: u64,
+    /// The index of the notification in its subscription, starting from 0.
+    pub sequence_number: u64,
+    /// The wall-clock time when the notification was received.
+    pub timestamp: SystemTime,
+}
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// This is an observable that emits the `Notification` values of `Materialize` with the subscription id, the sequence number and the timestamp of each, then completes.
+#[derive(Clone)]
+pub struct MaterializeWithMeta<O> {
+    source: O,
+}
+
+impl<O> MaterializeWithMeta<O> {
+    pub fn new(source: O) -> MaterializeWithMeta<O> {
+        MaterializeWithMeta { source }
+    }
+}
+
+impl<T, E, O> Observable<NotificationWithMeta<T, E>, Infallible> for MaterializeWithMeta<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(
+        self,
+        observer: impl Observer<NotificationWithMeta<T, E>, Infallible>,
+    ) -> Subscription {
+        let subscription_id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::SeqCst);
+        let sequence_number = AtomicU64::new(0);
+        let observer =
+            AnonymousObserver::new(move |event: Event<Notification<T, E>, Infallible>| {
+                observer.notify_if_unterminated(event.map_value(|notification| {
+                    NotificationWithMeta {
+                        notification,
+                        subscription_id,
+                        sequence_number: sequence_number.fetch_add(1, Ordering::SeqCst),
+                        timestamp: SystemTime::now(),
+                    }
+                }))
+            });
+        Materialize::new(self.source).subscribe(observer)
+    }
+}
+
+/// Make the `Observable` materializable.
+pub trait MaterializeObservable<T, E> {
+    /**
+    Emits the values and the terminated event as `Notification` values, then completes, so the stream can be logged, compared or stored. The errors become values, so the result never errors.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::materialize::MaterializeObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.materialize();
+    observable.subscribe_on_next(|notification| {
+        println!("{:?}", notification);
+    });
+    ```
+     */
+    fn materialize(self) -> impl Observable<Notification<T, E>, Infallible>;
+
+    /**
+    Like `materialize`, but wraps each `Notification` in `NotificationWithMeta` with the subscription id, the sequence number and the timestamp, so an audit log has the full provenance of each event.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::materialize::MaterializeObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.materialize_with_meta();
+    observable.subscribe_on_next(|item| {
+        println!(
+            "#{}/{} at {:?}: {:?}",
+            item.subscription_id, item.sequence_number, item.timestamp, item.notification
+        );
+    });
+    ```
+     */
+    fn materialize_with_meta(self) -> impl Observable<NotificationWithMeta<T, E>, Infallible>;
+}
+
+impl<O, T, E> MaterializeObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn materialize(self) -> impl Observable<Notification<T, E>, Infallible> {
+        Materialize::new(self)
+    }
+
+    fn materialize_with_meta(self) -> impl Observable<NotificationWithMeta<T, E>, Infallible> {
+        MaterializeWithMeta::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt,
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_completed() {
+        let checker = CheckingObserver::new();
+        Just::new(333).materialize().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[Notification::Next(333), Notification::Completed]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .materialize();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Notification::Next(333),
+            Notification::Error("error".to_owned()),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(333));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .materialize();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[Notification::Next(333)]));
+        assert!(checker.is_unsubscribed());
+    }
+
+    #[test]
+    fn test_with_meta() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .materialize_with_meta();
+        let first = Arc::new(Mutex::new(Vec::new()));
+        observable.clone().collect_into(first.clone());
+        let second = Arc::new(Mutex::new(Vec::new()));
+        observable.collect_into(second.clone());
+        let first = first.lock().unwrap();
+        let second = second.lock().unwrap();
+
+        let notifications: Vec<_> = first.iter().map(|item| item.notification.clone()).collect();
+        assert_eq!(
+            notifications,
+            vec![
+                Notification::Next(1),
+                Notification::Next(2),
+                Notification::Error("error".to_owned()),
+            ]
+        );
+        let sequence_numbers: Vec<_> = first.iter().map(|item| item.sequence_number).collect();
+        assert_eq!(sequence_numbers, vec![0, 1, 2]);
+        assert!(first
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+        assert!(first
+            .iter()
+            .all(|item| item.subscription_id == first[0].subscription_id));
+        assert_eq!(second.len(), 3);
+        assert_ne!(second[0].subscription_id, first[0].subscription_id);
+        assert_eq!(second[0].sequence_number, 0);
+    }
+}
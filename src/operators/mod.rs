@@ -1,5 +1,71 @@
+pub mod adaptive_buffer;
+pub mod buffer_count;
+pub mod buffer_time;
+pub mod buffer_toggle;
+pub mod checkpoint;
+pub mod cloned;
+pub mod concat_map;
+pub mod controllable;
 pub mod create;
+pub mod dedup_by_store;
+pub mod defer;
 pub mod delay;
+pub mod dematerialize;
+pub mod detect_gaps;
+pub mod diff_snapshots;
+pub mod distinct_within;
+pub mod emit_error_if;
+pub mod empty;
+pub mod exhaust_map;
+pub mod filter;
+pub mod filter_map;
+pub mod flat_map;
+pub mod flatten;
+pub mod flatten_iterable;
+#[cfg(feature = "tokio-scheduler")]
+pub mod from_future;
+pub mod from_iter;
+pub mod from_receiver;
+pub mod from_result;
+#[cfg(feature = "tokio-scheduler")]
+pub mod from_tokio_receiver;
+pub mod generate;
+#[cfg(feature = "tokio-scheduler")]
+pub mod graceful_shutdown;
+pub mod group_by;
+pub mod interval;
 pub mod just;
+pub mod keyed_latest;
 pub mod map;
+pub mod map_err;
+pub mod materialize;
+pub mod named;
+pub mod never;
+pub mod ordered_reassembly;
+pub mod range;
+pub mod rebatch;
+pub mod reduce;
+pub mod repeat;
+pub mod round_robin;
+pub mod scan;
+pub mod scan_map;
+pub mod select_ok;
+pub mod shard_by_key;
+pub mod split;
+pub mod split_at;
+pub mod stamp_age;
+pub mod start;
+pub mod suppress_repeated_errors;
+pub mod switch_map;
+pub mod synthetic;
+pub mod tap_subscription;
+pub mod terminate_when;
 pub mod throw;
+pub mod time_interval;
+pub mod timeout;
+pub mod timer;
+pub mod window_by_session;
+pub mod window_count;
+pub mod window_time;
+pub mod with_previous_n;
+pub mod zip_all;
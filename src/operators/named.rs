@@ -0,0 +1,160 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+    topology,
+};
+use std::sync::atomic::Ordering;
+
+/// This is an observable that forwards the source observable, and records each subscription as a stage of the `topology` while it's alive.
+#[derive(Clone)]
+pub struct Named<O> {
+    source: O,
+    name: String,
+}
+
+impl<O> Named<O> {
+    pub fn new(source: O, name: String) -> Named<O> {
+        Named { source, name }
+    }
+}
+
+impl<T, E, O> Observable<T, E> for Named<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let (id, values) = topology::register(&self.name);
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            match event {
+                Event::Next(_) => {
+                    values.fetch_add(1, Ordering::SeqCst);
+                }
+                Event::Terminated(_) => topology::unregister(id),
+            }
+            observer.notify_if_unterminated(event);
+        });
+        let source = self.source;
+        let subscription = topology::subscribing(id, move || source.subscribe(observer));
+        subscription.insert_disposal_action(move || topology::unregister(id))
+    }
+}
+
+/// Make the `Observable` visible in the `topology`.
+pub trait NamedObservable<T, E> {
+    /**
+    Names this stage of the pipeline. While subscribed, the stage is listed by `topology::dump()` and `topology::dump_graphviz()`, linked to the nearest named stage downstream of it, with the number of values that have passed through.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::map::MappableObservable;
+    use rx_rust::operators::named::NamedObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333).named("source").map(|value| value * 2).named("doubled");
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn named(self, name: impl Into<String>) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> NamedObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn named(self, name: impl Into<String>) -> impl Observable<T, E> {
+        Named::new(self, name.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        operators::{create::Create, just::Just, map::MappableObservable},
+        topology::Stage,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::{Arc, Mutex};
+
+    type SourceObservers = Arc<Mutex<Vec<Arc<Box<dyn Observer<i32, String>>>>>>;
+
+    fn source() -> (impl Observable<i32, String>, SourceObservers) {
+        let observers = Arc::new(Mutex::new(Vec::new()));
+        let observers_cloned = observers.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            let observer = Arc::new(observer);
+            observers_cloned.lock().unwrap().push(observer.clone());
+            Subscription::new_non_disposal_action(observer)
+        });
+        (observable, observers)
+    }
+
+    fn stage(name: &str) -> Option<Stage> {
+        topology::stages()
+            .into_iter()
+            .find(|stage| stage.name == name)
+    }
+
+    #[test]
+    fn test_topology() {
+        let (observable, observers) = source();
+        let observable = observable
+            .named("test_topology_source")
+            .map(|value| value * 2)
+            .named("test_topology_sink");
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        for observer in observers.lock().unwrap().iter() {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+        }
+        assert!(checker.is_values_matched(&[2, 4]));
+
+        let source_stage = stage("test_topology_source").unwrap();
+        let sink_stage = stage("test_topology_sink").unwrap();
+        assert_eq!(source_stage.downstream, Some(sink_stage.id));
+        assert_eq!(source_stage.values, 2);
+        assert!(topology::dump().contains(&format!(
+            "test_topology_sink #{} (2 values)\n  test_topology_source #{} (2 values)\n",
+            sink_stage.id, source_stage.id
+        )));
+        assert!(topology::dump_graphviz()
+            .contains(&format!("s{} -> s{};", source_stage.id, sink_stage.id)));
+
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(stage("test_topology_source").is_none());
+        assert!(stage("test_topology_sink").is_none());
+    }
+
+    #[test]
+    fn test_terminated() {
+        let checker = CheckingObserver::new();
+        let subscription = Just::new(333)
+            .named("test_terminated")
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        assert!(stage("test_terminated").is_none());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let (observable, observers) = source();
+        let checker = CheckingObserver::new();
+        let subscription = observable.named("test_error").subscribe(checker.clone());
+        assert!(stage("test_error").is_some());
+        for observer in observers.lock().unwrap().iter() {
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+        }
+        assert!(checker.is_error("error".to_owned()));
+        assert!(stage("test_error").is_none());
+        _ = subscription; // keep the subscription alive
+    }
+}
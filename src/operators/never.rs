@@ -0,0 +1,64 @@
+use crate::{observable::Observable, observer::Observer, subscription::Subscription};
+use std::marker::PhantomData;
+
+/**
+This is an observable that never emits and never terminates. The observer only receives the unsubscribed event when the subscription is unsubscribed or dropped.
+
+# Example
+```rust
+use rx_rust::operators::never::Never;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = Never::<i32, String>::new();
+let subscription = observable.subscribe_on_event(|event| println!("{:?}", event));
+subscription.unsubscribe();
+```
+*/
+pub struct Never<T, E> {
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T, E> Never<T, E> {
+    pub fn new() -> Never<T, E> {
+        Never {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E> Default for Never<T, E> {
+    fn default() -> Self {
+        Never::new()
+    }
+}
+
+impl<T, E> Clone for Never<T, E> {
+    fn clone(&self) -> Self {
+        Never::new()
+    }
+}
+
+impl<T, E> Observable<T, E> for Never<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+
+    #[test]
+    fn test_unterminated() {
+        let checker = CheckingObserver::<i32, String>::new();
+        let subscription = Never::new().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    operators::detect_gaps::{SequenceGap, Sequenced},
+    subscription::Subscription,
+};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that reorders the values of the source observable by sequence number, starting from `first_sequence_number`. If it is `None`, the values are held until more than `window` of them have arrived, and the lowest number held becomes the first one. After that, early values are held until the missing ones arrive; when more than `window` values are held, the missing numbers are skipped with a `Sequenced::Gap`.
+pub struct OrderedReassembly<T, O, F> {
+    source: O,
+    window: usize,
+    first_sequence_number: Option<u64>,
+    sequence_number: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> OrderedReassembly<T, O, F> {
+    pub fn new(
+        source: O,
+        window: usize,
+        first_sequence_number: Option<u64>,
+        sequence_number: F,
+    ) -> OrderedReassembly<T, O, F> {
+        OrderedReassembly {
+            source,
+            window,
+            first_sequence_number,
+            sequence_number: Arc::new(sequence_number),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for OrderedReassembly<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        OrderedReassembly {
+            source: self.source.clone(),
+            window: self.window,
+            first_sequence_number: self.first_sequence_number,
+            sequence_number: self.sequence_number.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct ReassemblyState<T> {
+    /// `None` until the first number is known.
+    expected: Option<u64>,
+    pending: BTreeMap<u64, T>,
+}
+
+impl<T> ReassemblyState<T> {
+    /// Take the values that are ready, skipping the missing numbers if `skip_missing` or the window has overflowed.
+    fn release(&mut self, window: usize, skip_missing: bool) -> Vec<Sequenced<T>> {
+        let mut released = Vec::new();
+        let overflowed = skip_missing || self.pending.len() > window;
+        let mut expected = match (self.expected, self.pending.first_key_value()) {
+            (Some(expected), _) => expected,
+            // The first number is the lowest one held once the window has overflowed.
+            (None, Some((&lowest, _))) if overflowed => lowest,
+            _ => return released,
+        };
+        loop {
+            match self.pending.first_key_value() {
+                Some((&received, _)) if received == expected => {}
+                Some((&received, _)) if skip_missing || self.pending.len() > window => {
+                    released.push(Sequenced::Gap(SequenceGap { expected, received }));
+                    expected = received;
+                }
+                _ => break,
+            }
+            let (_, value) = self.pending.pop_first().unwrap();
+            released.push(Sequenced::Value(value));
+            expected = expected.wrapping_add(1);
+        }
+        self.expected = Some(expected);
+        released
+    }
+}
+
+impl<T, E, O, F> Observable<Sequenced<T>, E> for OrderedReassembly<T, O, F>
+where
+    T: Sync + Send + 'static,
+    F: Fn(&T) -> u64 + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<Sequenced<T>, E>) -> Subscription {
+        let window = self.window;
+        let sequence_number = self.sequence_number.clone();
+        let state = Mutex::new(ReassemblyState {
+            expected: self.first_sequence_number,
+            pending: BTreeMap::new(),
+        });
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let received = sequence_number(&value);
+                let mut state = state.lock().unwrap();
+                if state.expected.is_some_and(|expected| received < expected) {
+                    // Too late, the number has been emitted or skipped.
+                    return;
+                }
+                state.pending.insert(received, value);
+                let released = state.release(window, false);
+                drop(state);
+                for item in released {
+                    observer.notify_if_unterminated(Event::Next(item));
+                }
+            }
+            Event::Terminated(Terminated::Completed) => {
+                let released = state.lock().unwrap().release(window, true);
+                for item in released {
+                    observer.notify_if_unterminated(Event::Next(item));
+                }
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` reorder its values by sequence number.
+pub trait OrderedReassemblyObservable<T, E> {
+    /**
+    Emits the values in the order of their sequence numbers, starting from `first_sequence_number`, so the values that arrive in order are emitted immediately. If the first number is unknown, pass `None`: the values are held until more than `window` of them have arrived, and the lowest number held becomes the first one, so the values that arrive out of order at the start are not lost. After that, a value that arrives early is held until the values before it have arrived. When more than `window` values are held, the missing numbers are given up with a `Sequenced::Gap` marker, and values that arrive after their number has been emitted or given up are dropped.
+    On completion, the held values are flushed in order with the gap markers in between.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::ordered_reassembly::OrderedReassemblyObservable;
+    use rx_rust::operators::detect_gaps::Sequenced;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new((1, "packet"));
+    let observable = observable.ordered_reassembly(16, Some(1), |(sequence_number, _)| *sequence_number);
+    observable.subscribe_on_next(|item| match item {
+        Sequenced::Value((_, value)) => println!("{}", value),
+        Sequenced::Gap(gap) => println!("lost {}..{}", gap.expected, gap.received),
+    });
+    ```
+     */
+    fn ordered_reassembly(
+        self,
+        window: usize,
+        first_sequence_number: Option<u64>,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<Sequenced<T>, E>
+    where
+        T: Sync + Send + 'static;
+}
+
+impl<O, T, E> OrderedReassemblyObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn ordered_reassembly(
+        self,
+        window: usize,
+        first_sequence_number: Option<u64>,
+        sequence_number: impl Fn(&T) -> u64 + Sync + Send + 'static,
+    ) -> impl Observable<Sequenced<T>, E>
+    where
+        T: Sync + Send + 'static,
+    {
+        OrderedReassembly::new(self, window, first_sequence_number, sequence_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source(values: Vec<u64>, terminated: Terminated<String>) -> impl Observable<u64, String> {
+        let terminated = Arc::new(Mutex::new(Some(terminated)));
+        Create::new(move |observer: Box<dyn Observer<u64, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            if let Some(terminated) = terminated.lock().unwrap().take() {
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    fn gap(expected: u64, received: u64) -> Sequenced<u64> {
+        Sequenced::Gap(SequenceGap { expected, received })
+    }
+
+    #[test]
+    fn test_reordered() {
+        let observable = source(vec![1, 3, 2, 5, 4], Terminated::Completed).ordered_reassembly(
+            8,
+            None,
+            |value| *value,
+        );
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(1),
+            Sequenced::Value(2),
+            Sequenced::Value(3),
+            Sequenced::Value(4),
+            Sequenced::Value(5),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_reordered_before_first() {
+        let observable =
+            source(vec![2, 1, 3], Terminated::Completed)
+                .ordered_reassembly(2, None, |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(1),
+            Sequenced::Value(2),
+            Sequenced::Value(3),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_held_until_window_overflow() {
+        let checker = CheckingObserver::new();
+        let observable = Create::new(|observer: Box<dyn Observer<u64, String>>| {
+            observer.notify_if_unterminated(Event::Next(3));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .ordered_reassembly(2, None, |value| *value);
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_in_order_from_first_sequence_number() {
+        let (emitter, observable) = HotObservable::<u64, String>::new();
+        let observable = observable.ordered_reassembly(8, Some(1), |value| *value);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        emitter.emit(1);
+        emitter.emit(2);
+        assert!(checker.is_values_matched(&[Sequenced::Value(1), Sequenced::Value(2)]));
+        emitter.emit(4);
+        assert!(checker.is_values_matched(&[Sequenced::Value(1), Sequenced::Value(2)]));
+        emitter.emit(3);
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(1),
+            Sequenced::Value(2),
+            Sequenced::Value(3),
+            Sequenced::Value(4),
+        ]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_first_sequence_number_missing() {
+        let observable =
+            source(vec![2, 3], Terminated::Completed)
+                .ordered_reassembly(1, Some(1), |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[gap(1, 2), Sequenced::Value(2), Sequenced::Value(3)]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_late_after_first() {
+        let observable =
+            source(vec![2, 3, 1, 4], Terminated::Completed)
+                .ordered_reassembly(1, None, |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(2),
+            Sequenced::Value(3),
+            Sequenced::Value(4),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_max_sequence_number() {
+        let observable = source(vec![u64::MAX - 1, u64::MAX], Terminated::Completed)
+            .ordered_reassembly(0, None, |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker
+            .is_values_matched(&[Sequenced::Value(u64::MAX - 1), Sequenced::Value(u64::MAX),]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_window_overflow() {
+        let observable = source(vec![1, 3, 4, 5, 2, 6], Terminated::Completed).ordered_reassembly(
+            2,
+            None,
+            |value| *value,
+        );
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(1),
+            gap(2, 3),
+            Sequenced::Value(3),
+            Sequenced::Value(4),
+            Sequenced::Value(5),
+            Sequenced::Value(6),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_flush_on_completed() {
+        let observable =
+            source(vec![1, 3, 6], Terminated::Completed)
+                .ordered_reassembly(8, None, |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            Sequenced::Value(1),
+            gap(2, 3),
+            Sequenced::Value(3),
+            gap(4, 6),
+            Sequenced::Value(6),
+        ]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = source(vec![1, 3], Terminated::Error("error".to_owned()))
+            .ordered_reassembly(1, None, |value| *value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[Sequenced::Value(1)]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+}
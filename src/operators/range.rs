@@ -0,0 +1,99 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::convert::Infallible;
+
+/**
+This is an observable that emits `count` consecutive integers starting from `start`, then completes.
+
+# Example
+```rust
+use rx_rust::operators::range::Range;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = Range::new(1, 3);
+observable.subscribe_on_next(|value| println!("{}", value)); // 1, 2, 3
+```
+*/
+#[derive(Clone)]
+pub struct Range {
+    start: i64,
+    count: usize,
+}
+
+impl Range {
+    /// Panics if the last value would overflow `i64`.
+    pub fn new(start: i64, count: usize) -> Range {
+        let fits = count == 0
+            || i64::try_from(count - 1)
+                .ok()
+                .and_then(|offset| start.checked_add(offset))
+                .is_some();
+        assert!(fits, "the range must not overflow i64");
+        Range { start, count }
+    }
+}
+
+impl Observable<i64, Infallible> for Range {
+    fn subscribe(self, observer: impl Observer<i64, Infallible>) -> Subscription {
+        for value in (0..self.count).map(|offset| self.start + offset as i64) {
+            if observer.terminated() {
+                break;
+            }
+            observer.notify_if_unterminated(Event::Next(value));
+        }
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::checking_observer::CheckingObserver;
+
+    #[test]
+    fn test_completed() {
+        let checker = CheckingObserver::new();
+        Range::new(-1, 4).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[-1, 0, 1, 2]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_empty() {
+        let checker = CheckingObserver::new();
+        Range::new(5, 0).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_max() {
+        let checker = CheckingObserver::new();
+        Range::new(i64::MAX - 1, 2).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[i64::MAX - 1, i64::MAX]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    #[should_panic(expected = "the range must not overflow i64")]
+    fn test_overflow() {
+        Range::new(i64::MAX, 2);
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = Range::new(1, 2);
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 2]));
+    }
+}
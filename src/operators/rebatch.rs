@@ -0,0 +1,162 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::sync::Mutex;
+
+/// This is an observable that splits and merges the batches of the source observable into batches of the target size. The remaining values are emitted as a smaller batch before the completed event.
+#[derive(Clone)]
+pub struct Rebatch<O> {
+    source: O,
+    target_size: usize,
+}
+
+impl<O> Rebatch<O> {
+    /// Panics if `target_size` is 0.
+    pub fn new(source: O, target_size: usize) -> Rebatch<O> {
+        assert!(
+            target_size > 0,
+            "the target size of rebatch must be positive"
+        );
+        Rebatch {
+            source,
+            target_size,
+        }
+    }
+}
+
+impl<T, E, O> Observable<Vec<T>, E> for Rebatch<O>
+where
+    O: Observable<Vec<T>, E>,
+    T: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let target_size = self.target_size;
+        let pending = Mutex::new(Vec::new());
+        let observer = AnonymousObserver::new(move |event: Event<Vec<T>, E>| match event {
+            Event::Next(batch) => {
+                let mut pending = pending.lock().unwrap();
+                pending.extend(batch);
+                // Drain all the full batches at once, so the remaining values are moved once.
+                let count = pending.len() / target_size;
+                let mut drain = pending.drain(..count * target_size);
+                let batches: Vec<Vec<T>> = (0..count)
+                    .map(|_| drain.by_ref().take(target_size).collect())
+                    .collect();
+                drop(drain);
+                drop(pending);
+                for batch in batches {
+                    observer.notify_if_unterminated(Event::Next(batch));
+                }
+            }
+            Event::Terminated(Terminated::Completed) => {
+                let rest = std::mem::take(&mut *pending.lock().unwrap());
+                if !rest.is_empty() {
+                    observer.notify_if_unterminated(Event::Next(rest));
+                }
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` of `Vec` values rebatchable.
+pub trait RebatchObservable<T, E> {
+    /**
+    Splits and merges the incoming batches into batches of `target_size` values, e.g. for a bulk-writing sink. The remaining values are emitted as a smaller batch before the completed event.
+
+    Panics if `target_size` is 0.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::rebatch::RebatchObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(vec![1, 2, 3, 4, 5]);
+    let observable = observable.rebatch(2);
+    observable.subscribe_on_next(|batch| {
+        println!("{:?}", batch);
+    });
+    ```
+     */
+    fn rebatch(self, target_size: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Send + 'static;
+}
+
+impl<O, T, E> RebatchObservable<T, E> for O
+where
+    O: Observable<Vec<T>, E>,
+{
+    fn rebatch(self, target_size: usize) -> impl Observable<Vec<T>, E>
+    where
+        T: Send + 'static,
+    {
+        Rebatch::new(self, target_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+
+    fn source(batches: Vec<Vec<i32>>, completed: bool) -> impl Observable<Vec<i32>, String> {
+        Create::new(move |observer: Box<dyn Observer<Vec<i32>, String>>| {
+            for batch in batches.iter() {
+                observer.notify_if_unterminated(Event::Next(batch.clone()));
+            }
+            if completed {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable =
+            source(vec![vec![1], vec![2, 3, 4, 5, 6, 7], vec![], vec![8]], true).rebatch(3);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_exact_batches() {
+        let observable = source(vec![vec![1, 2], vec![3, 4]], true).rebatch(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3, 4]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = source(vec![vec![1, 2, 3]], false).rebatch(2);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the target size of rebatch must be positive")]
+    fn test_zero_target_size() {
+        source(vec![], true).rebatch(0);
+    }
+}
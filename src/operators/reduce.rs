@@ -0,0 +1,137 @@
+use super::scan::Scan;
+use crate::observable::Observable;
+
+/// Make the `Observable` reducible.
+pub trait ReduceObservable<T, E> {
+    /**
+    Folds each value into the accumulator, starting from `seed`, and emits only the final accumulation right before the completion. It emits `seed` if the source completes without values. The errors are forwarded without emitting anything.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::reduce::ReduceObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.reduce(0, |sum, value| sum + value);
+    observable.subscribe_on_next(|sum| {
+        println!("{}", sum);
+    });
+    ```
+     */
+    fn reduce<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static;
+
+    /// The same as `reduce`.
+    fn fold<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> ReduceObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn reduce<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static,
+    {
+        Scan::new(self, seed, accumulator).final_only()
+    }
+
+    fn fold<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static,
+    {
+        Scan::new(self, seed, accumulator).final_only()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::{
+            event::{Event, Terminated},
+            Observer,
+        },
+        operators::{create::Create, empty::Empty, from_iter::FromIter},
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::convert::Infallible;
+
+    #[test]
+    fn test_completed() {
+        let checker = CheckingObserver::new();
+        FromIter::new(1..=4)
+            .reduce(0, |sum, value| sum + value)
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_empty() {
+        let checker = CheckingObserver::new();
+        Empty::<i32, Infallible>::new()
+            .fold(Vec::new(), |mut values, value| {
+                values.push(value);
+                values
+            })
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[Vec::<i32>::new()]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .reduce(0, |sum, value| sum + value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .reduce(0, |sum, value| sum + value);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unsubscribed());
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that resubscribes to the source observable each time it completes, forever or until it has completed `count` times. The errors are forwarded immediately.
+#[derive(Clone)]
+pub struct Repeat<O> {
+    source: O,
+    count: Option<usize>,
+}
+
+impl<O> Repeat<O> {
+    /// Repeat forever if `count` is `None`.
+    pub fn new(source: O, count: Option<usize>) -> Repeat<O> {
+        Repeat { source, count }
+    }
+}
+
+struct RepeatState {
+    stopped: bool,
+    completed: usize,
+    subscribing: bool,
+    resubscribe: bool,
+    source_subscription: Option<Subscription>,
+}
+
+struct Repeating<T, E, O, OR> {
+    source: O,
+    count: Option<usize>,
+    observer: Arc<OR>,
+    state: Mutex<RepeatState>,
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T, E, O, OR> Repeating<T, E, O, OR>
+where
+    O: Observable<T, E>,
+    OR: Observer<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    /// Subscribe to the source in a loop, so a source completing synchronously doesn't grow the stack.
+    fn subscribe_source(self: &Arc<Self>) {
+        loop {
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.stopped {
+                    return;
+                }
+                state.subscribing = true;
+                state.resubscribe = false;
+            }
+            let repeating = self.clone();
+            let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+                Event::Next(_) => repeating.observer.notify_if_unterminated(event),
+                Event::Terminated(Terminated::Completed) => repeating.on_completed(),
+                Event::Terminated(_) => repeating.finish(event),
+            });
+            let subscription = self.source.clone().subscribe(source_observer);
+            let mut state = self.state.lock().unwrap();
+            state.subscribing = false;
+            if state.stopped || state.resubscribe {
+                let resubscribe = !state.stopped;
+                drop(state);
+                drop(subscription);
+                if resubscribe {
+                    continue;
+                }
+                return;
+            }
+            let previous_subscription = state.source_subscription.replace(subscription);
+            drop(state);
+            drop(previous_subscription);
+            return;
+        }
+    }
+
+    fn on_completed(self: &Arc<Self>) {
+        let mut state = self.state.lock().unwrap();
+        state.completed += 1;
+        if Some(state.completed) == self.count {
+            drop(state);
+            self.finish(Event::Terminated(Terminated::Completed));
+        } else if state.subscribing {
+            // The loop in `subscribe_source` resubscribes once `subscribe` returns.
+            state.resubscribe = true;
+        } else {
+            drop(state);
+            self.subscribe_source();
+        }
+    }
+
+    fn finish(&self, event: Event<T, E>) {
+        let subscription = {
+            let mut state = self.state.lock().unwrap();
+            state.stopped = true;
+            state.source_subscription.take()
+        };
+        self.observer.notify_if_unterminated(event);
+        drop(subscription);
+    }
+}
+
+impl<T, E, O> Observable<T, E> for Repeat<O>
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        if self.count == Some(0) {
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            return Subscription::new_non_disposal_action(observer);
+        }
+        let repeating = Arc::new(Repeating {
+            source: self.source,
+            count: self.count,
+            observer: observer.clone(),
+            state: Mutex::new(RepeatState {
+                stopped: false,
+                completed: 0,
+                subscribing: false,
+                resubscribe: false,
+                source_subscription: None,
+            }),
+            _marker: PhantomData,
+        });
+        repeating.subscribe_source();
+        Subscription::new(observer, move || {
+            let subscription = {
+                let mut state = repeating.state.lock().unwrap();
+                state.stopped = true;
+                state.source_subscription.take()
+            };
+            drop(subscription);
+        })
+    }
+}
+
+/// Make the `Observable` repeatable.
+pub trait RepeatObservable<T, E> {
+    /**
+    Resubscribes to the source observable each time it completes, forever. The errors are forwarded immediately.
+
+    # Example
+    ```rust
+    use rx_rust::operators::create::Create;
+    use rx_rust::operators::repeat::RepeatObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::observer::event::{Event, Terminated};
+    use rx_rust::observer::Observer;
+    use rx_rust::subscription::Subscription;
+    let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+        observer.notify_if_unterminated(Event::Next(333));
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+        Subscription::new_non_disposal_action(observer)
+    });
+    let observable = observable.repeat();
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn repeat(self) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        E: Sync + Send + 'static;
+
+    /**
+    Subscribes to the source observable `count` times in a row, then completes. The errors are forwarded immediately.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::repeat::RepeatObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333).repeat_n(3);
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn repeat_n(self, count: usize) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        E: Sync + Send + 'static;
+}
+
+impl<O, T, E> RepeatObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn repeat(self) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        E: Sync + Send + 'static,
+    {
+        Repeat::new(self, None)
+    }
+
+    fn repeat_n(self, count: usize) -> impl Observable<T, E>
+    where
+        T: Sync + Send + 'static,
+        E: Sync + Send + 'static,
+    {
+        Repeat::new(self, Some(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, delay::DelayableObservable, just::Just},
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    #[test]
+    fn test_repeat_n() {
+        let checker = CheckingObserver::new();
+        Just::new(333).repeat_n(3).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333, 333, 333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_repeat_zero() {
+        let checker = CheckingObserver::new();
+        Just::new(333).repeat_n(0).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let subscribed = Arc::new(AtomicUsize::new(0));
+        let subscribed_cloned = subscribed.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            let count = subscribed_cloned.fetch_add(1, Ordering::SeqCst);
+            observer.notify_if_unterminated(Event::Next(count as i32));
+            if count == 1 {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Subscription::new_non_disposal_action(observer)
+        });
+        let checker = CheckingObserver::new();
+        observable.repeat().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[0, 1]));
+        assert!(checker.is_error("error".to_owned()));
+        assert_eq!(subscribed.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_asynchronous_source() {
+        let scheduler = QueueScheduler::new();
+        let observable = Just::new(333)
+            .delay(Duration::from_millis(10), scheduler.clone())
+            .repeat();
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        // Each round is a delayed value and a delayed completion.
+        for _ in 0..6 {
+            scheduler.run_one();
+        }
+        assert_eq!(scheduler.now(), Duration::from_millis(30));
+        assert!(checker.is_values_matched(&[333, 333, 333]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[333, 333, 333]));
+    }
+
+    #[test]
+    fn test_many_synchronous_repeats() {
+        let checker = CheckingObserver::new();
+        Just::new(1).repeat_n(100_000).subscribe(checker.clone());
+        assert!(checker.is_completed());
+    }
+}
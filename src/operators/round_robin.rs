@@ -0,0 +1,148 @@
+use super::split::SplitLane;
+use crate::observable::Observable;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Make the `Observable` splittable in rotation.
+pub trait RoundRobinObservable<T, E> {
+    /**
+    Splits the values into `count` lanes in rotation: the first value goes to the first lane, the second value to the second lane, and so on. The lanes without a subscriber are skipped, so no value is lost while any lane is subscribed. The terminated events go to every lane.
+    The source observable is subscribed once, when every lane has been subscribed, so subscribe all the lanes before expecting values. The source is unsubscribed when all the lane subscriptions are gone, and subscribed again when every lane has been subscribed again.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::round_robin::RoundRobinObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let lanes = observable.round_robin(3);
+    let subscriptions: Vec<_> = lanes
+        .into_iter()
+        .enumerate()
+        .map(|(worker, lane)| lane.subscribe_on_next(move |job| println!("worker {}: {}", worker, job)))
+        .collect();
+    ```
+     */
+    fn round_robin(self, count: usize) -> Vec<impl Observable<T, E>>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> RoundRobinObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn round_robin(self, count: usize) -> Vec<impl Observable<T, E>>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+    {
+        let position = AtomicUsize::new(0);
+        SplitLane::lanes_with_emitters(self, count, move |emitters| {
+            move |_: &T| {
+                let start = position.load(Ordering::SeqCst);
+                let index = (0..count)
+                    .map(|offset| (start + offset) % count)
+                    .find(|index| emitters[*index].observer_count() > 0)
+                    .unwrap_or(start % count);
+                // Continue the rotation after the chosen lane.
+                position.store(index + 1, Ordering::SeqCst);
+                index
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable,
+        observer::{
+            event::{Event, Terminated},
+            Observer,
+        },
+        operators::create::Create,
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::Arc;
+
+    fn source(subscriptions: Arc<AtomicUsize>) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            subscriptions.fetch_add(1, Ordering::SeqCst);
+            for value in 0..7 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_rotation() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let lanes = source(subscriptions.clone()).round_robin(3);
+        let checkers: Vec<_> = lanes
+            .into_iter()
+            .map(|lane| {
+                let checker = CheckingObserver::new();
+                let subscription = lane.subscribe(checker.clone());
+                (checker, subscription)
+            })
+            .collect();
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        let expected: [&[i32]; 3] = [&[0, 3, 6], &[1, 4], &[2, 5]];
+        for ((checker, _), expected) in checkers.iter().zip(expected) {
+            assert!(checker.is_values_matched(expected));
+            assert!(checker.is_completed());
+        }
+    }
+
+    #[test]
+    fn test_subscribe_lane_again() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let lanes = source(subscriptions.clone()).round_robin(2);
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        assert!(checker1.is_values_matched(&[1, 3, 5]));
+
+        // The source is not subscribed again, the lane is already completed.
+        let checker = CheckingObserver::new();
+        lanes[1].clone().subscribe(checker.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+        _ = (subscription0, subscription1); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_skip_lane_without_subscriber() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let lanes = observable.round_robin(3);
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        let subscription1 = lanes[1].clone().subscribe(CheckingObserver::new());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = lanes[2].clone().subscribe(checker2.clone());
+        subscription1.unsubscribe();
+        for value in 0..5 {
+            emitter.emit(value);
+        }
+        assert!(checker0.is_values_matched(&[0, 2, 4]));
+        assert!(checker2.is_values_matched(&[1, 3]));
+
+        // The lane takes its turn again once it is subscribed again.
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        for value in 5..8 {
+            emitter.emit(value);
+        }
+        assert!(checker1.is_values_matched(&[5]));
+        assert!(checker2.is_values_matched(&[1, 3, 6]));
+        assert!(checker0.is_values_matched(&[0, 2, 4, 7]));
+        _ = (subscription0, subscription1, subscription2); // keep the subscriptions alive
+    }
+}
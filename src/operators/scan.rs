@@ -0,0 +1,209 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that folds the values of the source observable into an accumulator, starting from a clone of `seed` for each subscription, and emits every intermediate accumulation.
+pub struct Scan<T, A, O, F> {
+    source: O,
+    seed: A,
+    accumulator: Arc<Mutex<F>>,
+    final_only: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T, A, O, F> Scan<T, A, O, F> {
+    pub fn new(source: O, seed: A, accumulator: F) -> Scan<T, A, O, F> {
+        Scan {
+            source,
+            seed,
+            accumulator: Arc::new(Mutex::new(accumulator)),
+            final_only: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Emit only the final accumulation, right before the completion.
+    pub(crate) fn final_only(mut self) -> Scan<T, A, O, F> {
+        self.final_only = true;
+        self
+    }
+}
+
+impl<T, A, O, F> Clone for Scan<T, A, O, F>
+where
+    A: Clone,
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        Scan {
+            source: self.source.clone(),
+            seed: self.seed.clone(),
+            accumulator: self.accumulator.clone(),
+            final_only: self.final_only,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, A, O, F> Observable<A, E> for Scan<T, A, O, F>
+where
+    T: Sync + Send + 'static,
+    A: Clone + Sync + Send + 'static,
+    F: FnMut(A, T) -> A + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<A, E>) -> Subscription {
+        let accumulator = self.accumulator.clone();
+        let final_only = self.final_only;
+        let state = Mutex::new(Some(self.seed));
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = state.lock().unwrap();
+                let Some(acc) = state.take() else {
+                    // The accumulator panicked on a previous value.
+                    return;
+                };
+                let acc = (accumulator.lock().unwrap())(acc, value);
+                if final_only {
+                    *state = Some(acc);
+                    return;
+                }
+                *state = Some(acc.clone());
+                drop(state);
+                observer.notify_if_unterminated(Event::Next(acc));
+            }
+            Event::Terminated(Terminated::Completed) if final_only => {
+                let acc = state.lock().unwrap().take();
+                if let Some(acc) = acc {
+                    observer.notify_if_unterminated(Event::Next(acc));
+                }
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` scannable.
+pub trait ScanObservable<T, E> {
+    /**
+    Folds each value into the accumulator, starting from `seed`, and emits every intermediate accumulation.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::scan::ScanObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.scan(0, |sum, value| sum + value);
+    observable.subscribe_on_next(|sum| {
+        println!("{}", sum);
+    });
+    ```
+     */
+    fn scan<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> ScanObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn scan<A>(
+        self,
+        seed: A,
+        accumulator: impl FnMut(A, T) -> A + Send + 'static,
+    ) -> impl Observable<A, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static,
+    {
+        Scan::new(self, seed, accumulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+
+    fn source() -> impl Observable<i32, String> {
+        Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            for value in 1..=3 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = source().scan(0, |sum, value| sum + value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1, 3, 6]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .scan(10, |sum, value| sum + value);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[11]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let mut calls = 0;
+        let observable = source().scan(Vec::new(), move |mut history, value| {
+            calls += 1;
+            history.push((value, calls));
+            history
+        });
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            vec![(1, 1)],
+            vec![(1, 1), (2, 2)],
+            vec![(1, 1), (2, 2), (3, 3)],
+        ]));
+
+        // Each subscription starts from the seed, while the closure keeps its own state.
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            vec![(1, 4)],
+            vec![(1, 4), (2, 5)],
+            vec![(1, 4), (2, 5), (3, 6)],
+        ]));
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that folds the values of the source observable into an accumulator, and emits the output returned together with each new accumulator.
+pub struct ScanMap<T, A, O, F> {
+    source: O,
+    seed: A,
+    accumulator: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, A, O, F> ScanMap<T, A, O, F> {
+    pub fn new(source: O, seed: A, accumulator: F) -> ScanMap<T, A, O, F> {
+        ScanMap {
+            source,
+            seed,
+            accumulator: Arc::new(accumulator),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, A, O, F> Clone for ScanMap<T, A, O, F>
+where
+    A: Clone,
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        ScanMap {
+            source: self.source.clone(),
+            seed: self.seed.clone(),
+            accumulator: self.accumulator.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, U, E, A, O, F> Observable<U, E> for ScanMap<T, A, O, F>
+where
+    T: Sync + Send + 'static,
+    A: Clone + Sync + Send + 'static,
+    F: Fn(A, T) -> (A, U) + Sync + Send + 'static,
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<U, E>) -> Subscription {
+        let accumulator = self.accumulator.clone();
+        let state = Mutex::new(Some(self.seed));
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = state.lock().unwrap();
+                let (acc, output) = accumulator(state.take().unwrap(), value);
+                *state = Some(acc);
+                drop(state);
+                observer.notify_if_unterminated(Event::Next(output));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` scannable with a separate output.
+pub trait ScanMapObservable<T, E> {
+    /**
+    Folds each value into the accumulator, starting from `seed`. The closure takes the accumulator by value and returns the new accumulator with the value to emit, so the accumulator does not need to be cloned for each output.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::scan_map::ScanMapObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.scan_map(0, |count, value| (count + 1, format!("#{}: {}", count + 1, value)));
+    observable.subscribe_on_next(|output| {
+        println!("{}", output);
+    });
+    ```
+     */
+    fn scan_map<A, U>(
+        self,
+        seed: A,
+        accumulator: impl Fn(A, T) -> (A, U) + Sync + Send + 'static,
+    ) -> impl Observable<U, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> ScanMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn scan_map<A, U>(
+        self,
+        seed: A,
+        accumulator: impl Fn(A, T) -> (A, U) + Sync + Send + 'static,
+    ) -> impl Observable<U, E>
+    where
+        T: Sync + Send + 'static,
+        A: Clone + Sync + Send + 'static,
+    {
+        ScanMap::new(self, seed, accumulator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source() -> impl Observable<i32, String> {
+        Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            for value in 1..=3 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_completed() {
+        let observable = source().scan_map(Vec::new(), |mut history, value| {
+            history.push(value);
+            let len = history.len();
+            (history, len * 10)
+        });
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10, 20, 30]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .scan_map(0, |sum, value| (sum + value, sum + value));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = source().scan_map(0, |sum, value| (sum + value, sum.to_string()));
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&["0".to_owned(), "1".to_owned(), "3".to_owned()]));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&["0".to_owned(), "1".to_owned(), "3".to_owned()]));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::sync::{Arc, Mutex};
+
+/**
+This is an observable that subscribes to all the sources and emits the first value from any of them, then completes and unsubscribes the others.
+It errors when every source has terminated without a value and at least one of them has errored, the error is a `Vec` of the errors in the order of the sources. The sources that complete without a value are left out of it.
+If every source completes without a value, it completes without value.
+
+# Example
+```rust
+use rx_rust::operators::just::Just;
+use rx_rust::operators::select_ok::SelectOk;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = SelectOk::new([Just::new(1), Just::new(2), Just::new(3)]);
+observable.subscribe_on_event(|event| println!("event: {:?}", event));
+```
+ */
+#[derive(Clone)]
+pub struct SelectOk<O> {
+    sources: Vec<O>,
+}
+
+impl<O> SelectOk<O> {
+    pub fn new(sources: impl IntoIterator<Item = O>) -> SelectOk<O> {
+        SelectOk {
+            sources: sources.into_iter().collect(),
+        }
+    }
+}
+
+struct SelectOkState<E> {
+    finished: bool,
+    completed_empty: usize,
+    errors: Vec<Option<E>>,
+    subscriptions: Vec<Subscription>,
+}
+
+impl<E> SelectOkState<E> {
+    fn all_terminated(&self) -> bool {
+        let errored = self.errors.iter().filter(|error| error.is_some()).count();
+        errored + self.completed_empty == self.errors.len()
+    }
+
+    /// The terminated event once every source has terminated without a value.
+    fn terminated(&mut self) -> Option<Terminated<Vec<E>>> {
+        if !self.all_terminated() {
+            return None;
+        }
+        self.finished = true;
+        let errors: Vec<E> = self.errors.drain(..).flatten().collect();
+        if errors.is_empty() {
+            Some(Terminated::Completed)
+        } else {
+            Some(Terminated::Error(errors))
+        }
+    }
+}
+
+impl<T, E, O> Observable<T, Vec<E>> for SelectOk<O>
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Vec<E>>) -> Subscription {
+        let observer = Arc::new(observer);
+        let count = self.sources.len();
+        if count == 0 {
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            return Subscription::new_non_disposal_action(observer);
+        }
+        let state = Arc::new(Mutex::new(SelectOkState {
+            finished: false,
+            completed_empty: 0,
+            errors: (0..count).map(|_| None).collect(),
+            subscriptions: Vec::new(),
+        }));
+        for (index, source) in self.sources.into_iter().enumerate() {
+            if state.lock().unwrap().finished {
+                break;
+            }
+            let observer = observer.clone();
+            let state_cloned = state.clone();
+            let source_observer = AnonymousObserver::new(move |event: Event<T, E>| {
+                let mut state_guard = state_cloned.lock().unwrap();
+                if state_guard.finished {
+                    return;
+                }
+                let terminated = match event {
+                    Event::Next(value) => {
+                        state_guard.finished = true;
+                        let subscriptions = std::mem::take(&mut state_guard.subscriptions);
+                        drop(state_guard);
+                        observer.notify_if_unterminated(Event::Next(value));
+                        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                        drop(subscriptions);
+                        return;
+                    }
+                    Event::Terminated(Terminated::Error(error)) => {
+                        state_guard.errors[index] = Some(error);
+                        state_guard.terminated()
+                    }
+                    Event::Terminated(Terminated::Completed) => {
+                        state_guard.completed_empty += 1;
+                        state_guard.terminated()
+                    }
+                    Event::Terminated(Terminated::Unsubscribed) => None,
+                };
+                drop(state_guard);
+                if let Some(terminated) = terminated {
+                    observer.notify_if_unterminated(Event::Terminated(terminated));
+                }
+            });
+            let subscription = source.subscribe(source_observer);
+            // Checked under the same lock as the winner takes the subscriptions, so none is left behind.
+            let mut state_guard = state.lock().unwrap();
+            if state_guard.finished {
+                drop(state_guard);
+                drop(subscription);
+            } else {
+                state_guard.subscriptions.push(subscription);
+            }
+        }
+        Subscription::new(observer, move || {
+            let subscriptions = std::mem::take(&mut state.lock().unwrap().subscriptions);
+            drop(subscriptions);
+        })
+    }
+}
+
+#[cfg(feature = "tokio-scheduler")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable,
+        operators::{create::Create, delay::DelayableObservable, just::Just, throw::Throw},
+        scheduler::tokio_scheduler::TokioScheduler,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_first_success() {
+        let observable = SelectOk::new([Just::new(1), Just::new(2)]);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_all_errors() {
+        let observable = SelectOk::new([Throw::new("a"), Throw::new("b")]);
+        let checker = CheckingObserver::<std::convert::Infallible, Vec<&str>>::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error(vec!["a", "b"]));
+    }
+
+    #[test]
+    fn test_empty_sources() {
+        let observable = SelectOk::new(Vec::<Just<i32>>::new());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error_then_success() {
+        fn source(fail: bool) -> impl Observable<i32, String> {
+            Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+                if fail {
+                    observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                        "error".to_owned(),
+                    )));
+                } else {
+                    observer.notify_if_unterminated(Event::Next(333));
+                }
+                Subscription::new_non_disposal_action(observer)
+            })
+        }
+        let observable = SelectOk::new([source(true), source(false)]);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_errors_with_empty_sources() {
+        fn source(terminated: Terminated<&'static str>) -> impl Observable<i32, &'static str> {
+            Create::new(move |observer: Box<dyn Observer<i32, &'static str>>| {
+                observer.notify_if_unterminated(Event::Terminated(terminated.clone()));
+                Subscription::new_non_disposal_action(observer)
+            })
+        }
+        let observable = SelectOk::new([
+            source(Terminated::Error("a")),
+            source(Terminated::Completed),
+            source(Terminated::Error("b")),
+        ]);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error(vec!["a", "b"]));
+    }
+
+    #[test]
+    fn test_unsubscribe_losers() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let observable = SelectOk::new([observable.clone(), observable]);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert_eq!(emitter.observer_count(), 2);
+        emitter.emit(1);
+        assert_eq!(emitter.observer_count(), 0);
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[tokio::test]
+    async fn test_fastest_wins() {
+        let slow = Just::new(1).delay(Duration::from_millis(20), TokioScheduler::new());
+        let fast = Just::new(2).delay(Duration::from_millis(5), TokioScheduler::new());
+        let observable = SelectOk::new([slow.clone(), fast]);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_unterminated());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(checker.is_values_matched(&[2]));
+        assert!(checker.is_completed());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(checker.is_values_matched(&[2]));
+        _ = subscription; // keep the subscription alive
+    }
+}
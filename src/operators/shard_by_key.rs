@@ -0,0 +1,170 @@
+use super::split::SplitLane;
+use crate::observable::Observable;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+fn shard_of(key: impl Hash, count: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() % count as u64) as usize
+}
+
+/// Make the `Observable` shardable by key.
+pub trait ShardByKeyObservable<T, E> {
+    /**
+    Splits the values into `count` shards by the hash of their key. The values with the same key go to the same shard in order, so the shards can be consumed in parallel, e.g. on different schedulers, while keeping the per-key order. The terminated events go to every shard.
+    The source observable is subscribed once, when every shard has been subscribed, so subscribe all the shards before expecting values. A value whose shard has no subscriber at that moment is dropped. The source is unsubscribed when all the shard subscriptions are gone, and subscribed again when every shard has been subscribed again.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::shard_by_key::ShardByKeyObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(("user-1", 333));
+    let shards = observable.shard_by_key(4, |(user, _)| *user);
+    let subscriptions: Vec<_> = shards
+        .into_iter()
+        .enumerate()
+        .map(|(index, shard)| {
+            shard.subscribe_on_next(move |(user, value)| println!("shard {}: {} {}", index, user, value))
+        })
+        .collect();
+    ```
+     */
+    fn shard_by_key<K>(
+        self,
+        count: usize,
+        key: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> Vec<impl Observable<T, E>>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+        K: Hash;
+}
+
+impl<O, T, E> ShardByKeyObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn shard_by_key<K>(
+        self,
+        count: usize,
+        key: impl Fn(&T) -> K + Sync + Send + 'static,
+    ) -> Vec<impl Observable<T, E>>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+        K: Hash,
+    {
+        SplitLane::lanes(self, count, move |value: &T| shard_of(key(value), count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt,
+        observer::{
+            event::{Event, Terminated},
+            Observer,
+        },
+        operators::create::Create,
+        subscription::Subscription,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    type Received = Arc<Mutex<Vec<(char, i32)>>>;
+
+    fn source(subscriptions: Arc<AtomicUsize>) -> impl Observable<(char, i32), String> {
+        Create::new(move |observer: Box<dyn Observer<(char, i32), String>>| {
+            subscriptions.fetch_add(1, Ordering::SeqCst);
+            for (index, key) in "abcdabcdaa".chars().enumerate() {
+                observer.notify_if_unterminated(Event::Next((key, index as i32)));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_per_key_order() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let shards = source(subscriptions.clone()).shard_by_key(3, |(key, _)| *key);
+        assert_eq!(shards.len(), 3);
+        let (received, subscriptions_of_shards): (Vec<Received>, Vec<Subscription>) = shards
+            .into_iter()
+            .map(|shard| {
+                let values = Arc::new(Mutex::new(Vec::new()));
+                let subscription = shard.collect_into(values.clone());
+                (values, subscription)
+            })
+            .unzip();
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        let total: usize = received
+            .iter()
+            .map(|values| values.lock().unwrap().len())
+            .sum();
+        assert_eq!(total, 10);
+        for key in "abcd".chars() {
+            let shards_with_key: Vec<Vec<i32>> = received
+                .iter()
+                .map(|values| {
+                    values
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .filter(|(k, _)| *k == key)
+                        .map(|(_, index)| *index)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|indexes| !indexes.is_empty())
+                .collect();
+            assert_eq!(shards_with_key.len(), 1);
+            let indexes = &shards_with_key[0];
+            assert!(indexes.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+        _ = subscriptions_of_shards; // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_terminated_to_every_shard() {
+        let shards = source(Arc::new(AtomicUsize::new(0))).shard_by_key(4, |(key, _)| *key);
+        let checkers: Vec<_> = shards
+            .into_iter()
+            .map(|shard| {
+                let checker = CheckingObserver::new();
+                let subscription = shard.subscribe(checker.clone());
+                (checker, subscription)
+            })
+            .collect();
+        for (checker, _) in checkers.iter() {
+            assert!(checker.is_completed());
+        }
+    }
+
+    #[test]
+    fn test_single_shard() {
+        let mut shards = source(Arc::new(AtomicUsize::new(0))).shard_by_key(1, |(key, _)| *key);
+        let checker = CheckingObserver::new();
+        shards.remove(0).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[
+            ('a', 0),
+            ('b', 1),
+            ('c', 2),
+            ('d', 3),
+            ('a', 4),
+            ('b', 5),
+            ('c', 6),
+            ('d', 7),
+            ('a', 8),
+            ('a', 9),
+        ]));
+    }
+}
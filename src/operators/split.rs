@@ -0,0 +1,372 @@
+use crate::{
+    observable::{
+        hot_observable::{HotEmitter, HotObservable},
+        Observable,
+    },
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::sync::{Arc, Mutex};
+
+struct SplitState {
+    /// Whether each lane has been subscribed at least once in this round.
+    subscribed: Vec<bool>,
+    connected: bool,
+    /// Incremented when all the lane subscriptions are gone, so the next subscriptions connect to the source again.
+    round: u64,
+    stopped: bool,
+    /// The number of the live lane subscriptions.
+    active: usize,
+    source_subscription: Option<Subscription>,
+}
+
+impl SplitState {
+    /// Stop and take the source subscription, so it can be dropped outside the lock.
+    fn stop(&mut self) -> Option<Subscription> {
+        self.stopped = true;
+        self.source_subscription.take()
+    }
+}
+
+type MakeRoute<F> = Box<dyn Fn() -> Arc<F> + Sync + Send>;
+
+/// The part shared by all the lanes of a split.
+struct Splitter<T, E, O, F> {
+    source: O,
+    /// Makes the route of each round.
+    make_route: MakeRoute<F>,
+    emitters: Vec<HotEmitter<T, E>>,
+    state: Arc<Mutex<SplitState>>,
+}
+
+impl<T, E, O, F> Splitter<T, E, O, F>
+where
+    T: Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(&T) -> usize + Sync + Send + 'static,
+{
+    /// Subscribe to the source once in the round, routing each value to the emitter of its lane.
+    fn connect(&self, round: u64) {
+        let route = (self.make_route)();
+        let emitters = self.emitters.clone();
+        let state = self.state.clone();
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let index = route(&value);
+                emitters[index].emit(value);
+            }
+            Event::Terminated(terminated) => {
+                let mut state = state.lock().unwrap();
+                if state.round != round {
+                    // The round is over, its lanes are all gone.
+                    return;
+                }
+                let subscription = state.stop();
+                drop(state);
+                for emitter in emitters.iter() {
+                    match &terminated {
+                        Terminated::Error(error) => emitter.error(error.clone()),
+                        _ => emitter.complete(),
+                    }
+                }
+                drop(subscription);
+            }
+        });
+        let subscription = self.source.clone().subscribe(observer);
+        let mut state = self.state.lock().unwrap();
+        if state.stopped || state.round != round {
+            drop(state);
+            drop(subscription);
+        } else {
+            state.source_subscription = Some(subscription);
+        }
+    }
+}
+
+/// This is an observable of the values of one lane of a split. The source observable is subscribed once, when every lane has been subscribed, and each of its values goes to the lane chosen by the route. A value routed to a lane without any subscriber is dropped. The terminated event goes to every lane, and the lanes subscribed afterwards receive it immediately.
+/// The source is unsubscribed when all the lane subscriptions are gone, and it is subscribed again when every lane has been subscribed again.
+pub struct SplitLane<T, E, O, F> {
+    index: usize,
+    observable: HotObservable<T, E>,
+    splitter: Arc<Splitter<T, E, O, F>>,
+}
+
+impl<T, E, O, F> SplitLane<T, E, O, F>
+where
+    F: Sync + Send + 'static,
+{
+    /// Create `count` lanes of the source. `route` returns the index of the lane of each value, which must be less than `count`.
+    pub fn lanes(source: O, count: usize, route: F) -> Vec<SplitLane<T, E, O, F>> {
+        Self::lanes_with_emitters(source, count, |_| route)
+    }
+
+    /// Same as `lanes`, but the route is made from the emitters of the lanes, e.g. to check which lanes have a subscriber.
+    pub(crate) fn lanes_with_emitters(
+        source: O,
+        count: usize,
+        make_route: impl FnOnce(Vec<HotEmitter<T, E>>) -> F,
+    ) -> Vec<SplitLane<T, E, O, F>> {
+        Self::build(source, count, |emitters| {
+            let route = Arc::new(make_route(emitters));
+            Box::new(move || route.clone())
+        })
+    }
+
+    /// Same as `lanes`, but a new route is made each time the source is subscribed, e.g. to count the values of each subscription.
+    pub(crate) fn lanes_per_round(
+        source: O,
+        count: usize,
+        make_route: impl Fn() -> F + Sync + Send + 'static,
+    ) -> Vec<SplitLane<T, E, O, F>> {
+        Self::build(source, count, |_| Box::new(move || Arc::new(make_route())))
+    }
+
+    fn build(
+        source: O,
+        count: usize,
+        make_route: impl FnOnce(Vec<HotEmitter<T, E>>) -> MakeRoute<F>,
+    ) -> Vec<SplitLane<T, E, O, F>> {
+        let (emitters, observables): (Vec<HotEmitter<T, E>>, Vec<_>) =
+            (0..count).map(|_| HotObservable::new()).unzip();
+        let make_route = make_route(emitters.clone());
+        let splitter = Arc::new(Splitter {
+            source,
+            make_route,
+            emitters,
+            state: Arc::new(Mutex::new(SplitState {
+                subscribed: vec![false; count],
+                connected: false,
+                round: 0,
+                stopped: false,
+                active: 0,
+                source_subscription: None,
+            })),
+        });
+        observables
+            .into_iter()
+            .enumerate()
+            .map(|(index, observable)| SplitLane {
+                index,
+                observable,
+                splitter: splitter.clone(),
+            })
+            .collect()
+    }
+}
+
+impl<T, E, O, F> Clone for SplitLane<T, E, O, F> {
+    fn clone(&self) -> Self {
+        SplitLane {
+            index: self.index,
+            observable: self.observable.clone(),
+            splitter: self.splitter.clone(),
+        }
+    }
+}
+
+impl<T, E, O, F> Observable<T, E> for SplitLane<T, E, O, F>
+where
+    T: Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(&T) -> usize + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let lane_subscription = Mutex::new(self.observable.subscribe(observer.clone()));
+        let connect = {
+            let mut state = self.splitter.state.lock().unwrap();
+            state.active += 1;
+            state.subscribed[self.index] = true;
+            let connect = !state.connected && state.subscribed.iter().all(|subscribed| *subscribed);
+            state.connected |= connect;
+            connect.then_some(state.round)
+        };
+        if let Some(round) = connect {
+            self.splitter.connect(round);
+        }
+        let state = self.splitter.state.clone();
+        Subscription::new(observer, move || {
+            // Leave the lane first, so the observer is not completed by the source being unsubscribed.
+            drop(lane_subscription);
+            let source_subscription = {
+                let mut state = state.lock().unwrap();
+                state.active -= 1;
+                if state.active == 0 && !state.stopped {
+                    // Start a new round, the next subscriptions connect to the source again.
+                    state.round += 1;
+                    state.connected = false;
+                    state.subscribed.fill(false);
+                    state.source_subscription.take()
+                } else {
+                    None
+                }
+            };
+            drop(source_subscription);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A source of `0..6` that counts its subscriptions and unsubscriptions.
+    fn source(
+        subscriptions: Arc<AtomicUsize>,
+        disposals: Arc<AtomicUsize>,
+        end: Option<Terminated<String>>,
+    ) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            subscriptions.fetch_add(1, Ordering::SeqCst);
+            for value in 0..6 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            if let Some(end) = end.clone() {
+                observer.notify_if_unterminated(Event::Terminated(end));
+            }
+            let disposals = disposals.clone();
+            Subscription::new(observer, move || {
+                disposals.fetch_add(1, Ordering::SeqCst);
+            })
+        })
+    }
+
+    #[test]
+    fn test_subscribe_once() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let disposals = Arc::new(AtomicUsize::new(0));
+        let lanes = SplitLane::lanes(
+            source(
+                subscriptions.clone(),
+                disposals,
+                Some(Terminated::Completed),
+            ),
+            2,
+            |value: &i32| (value % 2) as usize,
+        );
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        // Nothing is emitted until every lane has been subscribed.
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 0);
+        assert!(checker0.is_values_matched(&[]));
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        assert!(checker0.is_values_matched(&[0, 2, 4]));
+        assert!(checker0.is_completed());
+        assert!(checker1.is_values_matched(&[1, 3, 5]));
+        assert!(checker1.is_completed());
+        _ = (subscription0, subscription1); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_error() {
+        let lanes = SplitLane::lanes(
+            source(
+                Arc::new(AtomicUsize::new(0)),
+                Arc::new(AtomicUsize::new(0)),
+                Some(Terminated::Error("error".to_owned())),
+            ),
+            2,
+            |value: &i32| (value % 2) as usize,
+        );
+        let checkers: Vec<_> = lanes
+            .iter()
+            .map(|lane| {
+                let checker = CheckingObserver::new();
+                let subscription = lane.clone().subscribe(checker.clone());
+                (checker, subscription)
+            })
+            .collect();
+        assert!(checkers[0].0.is_error("error".to_owned()));
+        assert!(checkers[1].0.is_error("error".to_owned()));
+
+        // A lane subscribed afterwards gets the error right away.
+        let checker = CheckingObserver::new();
+        lanes[0].clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unsubscribe_all_lanes() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let disposals = Arc::new(AtomicUsize::new(0));
+        let lanes = SplitLane::lanes(
+            source(subscriptions.clone(), disposals.clone(), None),
+            2,
+            |value: &i32| (value % 2) as usize,
+        );
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        subscription0.unsubscribe();
+        assert_eq!(disposals.load(Ordering::SeqCst), 0);
+        assert!(checker0.is_unsubscribed());
+        assert!(checker1.is_unterminated());
+        subscription1.unsubscribe();
+        assert_eq!(disposals.load(Ordering::SeqCst), 1);
+        assert!(checker1.is_values_matched(&[1, 3, 5]));
+        assert!(checker1.is_unsubscribed());
+    }
+
+    #[test]
+    fn test_subscribe_again_after_unsubscribing_all_lanes() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let disposals = Arc::new(AtomicUsize::new(0));
+        let lanes = SplitLane::lanes(
+            source(subscriptions.clone(), disposals.clone(), None),
+            2,
+            |value: &i32| (value % 2) as usize,
+        );
+        let subscription0 = lanes[0].clone().subscribe(CheckingObserver::new());
+        let subscription1 = lanes[1].clone().subscribe(CheckingObserver::new());
+        subscription0.unsubscribe();
+        subscription1.unsubscribe();
+        assert_eq!(disposals.load(Ordering::SeqCst), 1);
+
+        // The lanes subscribed afterwards connect to the source again.
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 2);
+        assert!(checker0.is_values_matched(&[0, 2, 4]));
+        assert!(checker1.is_values_matched(&[1, 3, 5]));
+        assert!(checker0.is_unterminated());
+        assert!(checker1.is_unterminated());
+        _ = (subscription0, subscription1); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_drop_values_of_lane_without_subscriber() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let lanes = SplitLane::lanes(observable, 2, |value: &i32| (value % 2) as usize);
+        let checker0 = CheckingObserver::new();
+        let subscription0 = lanes[0].clone().subscribe(checker0.clone());
+        let subscription1 = lanes[1].clone().subscribe(CheckingObserver::new());
+        subscription1.unsubscribe();
+        emitter.emit(0);
+        emitter.emit(1);
+
+        // The lane gets the values routed to it after it is subscribed again.
+        let checker1 = CheckingObserver::new();
+        let subscription1 = lanes[1].clone().subscribe(checker1.clone());
+        emitter.emit(2);
+        emitter.emit(3);
+        assert!(checker0.is_values_matched(&[0, 2]));
+        assert!(checker1.is_values_matched(&[3]));
+        _ = (subscription0, subscription1); // keep the subscriptions alive
+    }
+}
@@ -0,0 +1,188 @@
+use super::split::SplitLane;
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// This is an observable of the first `count` values of a split. It completes after the `count`th value, but keeps its lane subscribed until it is unsubscribed, so the rest of the split keeps running.
+#[derive(Clone)]
+pub struct SplitAtPrefix<L> {
+    lane: L,
+    count: usize,
+}
+
+impl<T, E, L> Observable<T, E> for SplitAtPrefix<L>
+where
+    L: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let count = self.count;
+        if count == 0 {
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        }
+        let received = AtomicUsize::new(0);
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                observer.notify_if_unterminated(Event::Next(value));
+                if received.fetch_add(1, Ordering::SeqCst) + 1 == count {
+                    observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                }
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.lane.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` splittable at an index.
+pub trait SplitAtObservable<T, E> {
+    /**
+    Splits the values into the first `count` values and the rest, e.g. a handshake prefix and a body stream handled by different components. The first observable completes after the `count`th value, and the terminated events of the source go to both.
+    The source observable is subscribed once, when both observables have been subscribed, so subscribe both before expecting values. A value whose observable has no subscriber at that moment is dropped. The source is unsubscribed when both subscriptions are gone, and subscribed again, counting from the start, when both have been subscribed again.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::split_at::SplitAtObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(vec![1, 2, 3, 4, 5]);
+    let (handshake, body) = observable.split_at(2);
+    let subscription1 = handshake.subscribe_on_next(|value| println!("handshake: {}", value));
+    let subscription2 = body.subscribe_on_next(|value| println!("body: {}", value));
+    ```
+     */
+    fn split_at(self, count: usize) -> (impl Observable<T, E>, impl Observable<T, E>)
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> SplitAtObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn split_at(self, count: usize) -> (impl Observable<T, E>, impl Observable<T, E>)
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+    {
+        let mut lanes = SplitLane::lanes_per_round(self, 2, move || {
+            let position = AtomicUsize::new(0);
+            move |_: &T| {
+                let in_prefix = position
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |position| {
+                        (position < count).then_some(position + 1)
+                    })
+                    .is_ok();
+                if in_prefix {
+                    0
+                } else {
+                    1
+                }
+            }
+        });
+        let rest = lanes.pop().unwrap();
+        let lane = lanes.pop().unwrap();
+        (SplitAtPrefix { lane, count }, rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::hot_observable::HotObservable, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::Arc;
+
+    fn source(subscriptions: Arc<AtomicUsize>) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            subscriptions.fetch_add(1, Ordering::SeqCst);
+            for value in 0..5 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_split() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let (first, rest) = source(subscriptions.clone()).split_at(2);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = first.subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = rest.subscribe(checker2.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        assert!(checker1.is_values_matched(&[0, 1]));
+        assert!(checker1.is_completed());
+        assert!(checker2.is_values_matched(&[2, 3, 4]));
+        assert!(checker2.is_completed());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_complete_first_before_source() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let (first, rest) = observable.split_at(1);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = first.subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = rest.subscribe(checker2.clone());
+        emitter.emit(1);
+        assert!(checker1.is_values_matched(&[1]));
+        assert!(checker1.is_completed());
+        emitter.emit(2);
+        assert!(checker2.is_values_matched(&[2]));
+        assert!(checker2.is_unterminated());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_zero() {
+        let subscriptions = Arc::new(AtomicUsize::new(0));
+        let (first, rest) = source(subscriptions.clone()).split_at(0);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = first.subscribe(checker1.clone());
+        assert!(checker1.is_completed());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = rest.subscribe(checker2.clone());
+        assert_eq!(subscriptions.load(Ordering::SeqCst), 1);
+        assert!(checker1.is_values_matched(&[]));
+        assert!(checker2.is_values_matched(&[0, 1, 2, 3, 4]));
+        assert!(checker2.is_completed());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_count_again_after_resubscribing() {
+        let (emitter, observable) = HotObservable::<i32, String>::new();
+        let (first, rest) = observable.split_at(1);
+        let subscription1 = first.clone().subscribe(CheckingObserver::new());
+        let subscription2 = rest.clone().subscribe(CheckingObserver::new());
+        emitter.emit(1);
+        emitter.emit(2);
+        subscription1.unsubscribe();
+        subscription2.unsubscribe();
+
+        let checker1 = CheckingObserver::new();
+        let subscription1 = first.subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = rest.subscribe(checker2.clone());
+        emitter.emit(3);
+        emitter.emit(4);
+        assert!(checker1.is_values_matched(&[3]));
+        assert!(checker2.is_values_matched(&[4]));
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::time::{Duration, Instant};
+
+/// A value with the instant it entered the chain, emitted by `stamp_age`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Aged<T> {
+    pub value: T,
+    pub ingested: Instant,
+}
+
+impl<T> Aged<T> {
+    /// The time elapsed since the value entered the chain.
+    pub fn age(&self) -> Duration {
+        self.ingested.elapsed()
+    }
+}
+
+/// This is an observable that wraps the values of the source observable in `Aged` with the current instant.
+#[derive(Clone)]
+pub struct StampAge<O> {
+    source: O,
+}
+
+impl<O> StampAge<O> {
+    pub fn new(source: O) -> StampAge<O> {
+        StampAge { source }
+    }
+}
+
+impl<T, E, O> Observable<Aged<T>, E> for StampAge<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<Aged<T>, E>) -> Subscription {
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            observer.notify_if_unterminated(event.map_value(|value| Aged {
+                value,
+                ingested: Instant::now(),
+            }))
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// This is an observable that drops the `Aged` values of the source observable older than the max age when they arrive.
+#[derive(Clone)]
+pub struct DropOlderThan<O> {
+    source: O,
+    max_age: Duration,
+}
+
+impl<O> DropOlderThan<O> {
+    pub fn new(source: O, max_age: Duration) -> DropOlderThan<O> {
+        DropOlderThan { source, max_age }
+    }
+}
+
+impl<T, E, O> Observable<Aged<T>, E> for DropOlderThan<O>
+where
+    O: Observable<Aged<T>, E>,
+{
+    fn subscribe(self, observer: impl Observer<Aged<T>, E>) -> Subscription {
+        let max_age = self.max_age;
+        let observer = AnonymousObserver::new(move |event: Event<Aged<T>, E>| match event {
+            Event::Next(aged) if aged.age() > max_age => {}
+            _ => observer.notify_if_unterminated(event),
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` stamp its values with their ingest instant.
+pub trait StampAgeObservable<T, E> {
+    /**
+    Wraps each value in `Aged` with the instant it arrived, so later stages can check how stale it is.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::stamp_age::StampAgeObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.stamp_age();
+    observable.subscribe_on_next(|aged| {
+        println!("{} is {:?} old", aged.value, aged.age());
+    });
+    ```
+     */
+    fn stamp_age(self) -> impl Observable<Aged<T>, E>;
+}
+
+impl<O, T, E> StampAgeObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn stamp_age(self) -> impl Observable<Aged<T>, E> {
+        StampAge::new(self)
+    }
+}
+
+/// Make the `Observable` of `Aged` values drop the stale ones.
+pub trait DropOlderThanObservable<T, E> {
+    /**
+    Drops the values older than `max_age` when they arrive, e.g. after waiting in a queue or a delay.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::stamp_age::{DropOlderThanObservable, StampAgeObservable};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use std::time::Duration;
+    let observable = Just::new(333);
+    let observable = observable.stamp_age().drop_older_than(Duration::from_secs(1));
+    observable.subscribe_on_next(|aged| {
+        println!("{}", aged.value);
+    });
+    ```
+     */
+    fn drop_older_than(self, max_age: Duration) -> impl Observable<Aged<T>, E>;
+}
+
+impl<O, T, E> DropOlderThanObservable<T, E> for O
+where
+    O: Observable<Aged<T>, E>,
+{
+    fn drop_older_than(self, max_age: Duration) -> impl Observable<Aged<T>, E> {
+        DropOlderThan::new(self, max_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt, observer::event::Terminated,
+        operators::create::Create, utils::checking_observer::CheckingObserver,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_stamp_age() {
+        let before = Instant::now();
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .stamp_age();
+        let values = Arc::new(Mutex::new(Vec::new()));
+        observable.collect_into(values.clone());
+        let after = Instant::now();
+        let values = values.lock().unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].value, 1);
+        assert!(before <= values[0].ingested && values[0].ingested <= after);
+    }
+
+    #[test]
+    fn test_drop_older_than() {
+        let now = Instant::now();
+        let aged = |value, age| Aged {
+            value,
+            ingested: now - Duration::from_secs(age),
+        };
+        let events = [aged(1, 0), aged(2, 120), aged(3, 30)];
+        let observable = Create::new(move |observer: Box<dyn Observer<Aged<i32>, String>>| {
+            for aged in events.iter() {
+                observer.notify_if_unterminated(Event::Next(aged.clone()));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .drop_older_than(Duration::from_secs(60));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[aged(1, 0), aged(3, 30)]));
+        assert!(checker.is_completed());
+    }
+}
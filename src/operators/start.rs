@@ -0,0 +1,151 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+/**
+This is an observable that calls the function on each subscription, emits its result, then completes.
+
+# Example
+```rust
+use rx_rust::operators::start::Start;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = Start::new(|| std::fs::read_to_string("Cargo.toml").map(|text| text.len()));
+observable.subscribe_on_next(|length| println!("{:?}", length));
+```
+*/
+pub struct Start<F> {
+    f: Arc<Mutex<F>>,
+}
+
+impl<F> Start<F> {
+    pub fn new(f: F) -> Start<F> {
+        Start {
+            f: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Call the function in a task on the scheduler instead of on the subscribing thread, e.g. for a blocking computation.
+    pub fn scheduled<S>(self, scheduler: S) -> ScheduledStart<F, S> {
+        ScheduledStart {
+            f: self.f,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<F> Clone for Start<F> {
+    fn clone(&self) -> Self {
+        Start { f: self.f.clone() }
+    }
+}
+
+impl<T, F> Observable<T, Infallible> for Start<F>
+where
+    F: FnMut() -> T + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let value = (self.f.lock().unwrap())();
+        observer.notify_if_unterminated(Event::Next(value));
+        observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        Subscription::new_non_disposal_action(observer)
+    }
+}
+
+/// This is an observable like `Start`, which calls the function in a task on the scheduler. The task is cancelled if the subscription is unsubscribed or dropped before it runs.
+pub struct ScheduledStart<F, S> {
+    f: Arc<Mutex<F>>,
+    scheduler: Arc<S>,
+}
+
+impl<F, S> Clone for ScheduledStart<F, S> {
+    fn clone(&self) -> Self {
+        ScheduledStart {
+            f: self.f.clone(),
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+impl<T, F, S> Observable<T, Infallible> for ScheduledStart<F, S>
+where
+    F: FnMut() -> T + Send + 'static,
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let observer_cloned = observer.clone();
+        let f = self.f.clone();
+        let disposal = self.scheduler.schedule(
+            move || {
+                if observer_cloned.terminated() {
+                    return;
+                }
+                let value = (f.lock().unwrap())();
+                observer_cloned.notify_if_unterminated(Event::Next(value));
+                observer_cloned.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            },
+            None,
+        );
+        let disposal = Mutex::new(disposal.to_boxed());
+        Subscription::new(observer, move || drop(disposal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler, utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_per_subscription() {
+        let mut count = 0;
+        let observable = Start::new(move || {
+            count += 1;
+            count
+        });
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_completed());
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[2]));
+    }
+
+    #[test]
+    fn test_scheduled() {
+        let scheduler = QueueScheduler::new();
+        let observable = Start::new(|| 333).scheduled(scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_scheduled_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let observable = Start::new(|| 333).scheduled(scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// An error value emitted by `suppress_repeated_errors`, with the number of identical errors swallowed since the last time this error was emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepeatedError<X> {
+    pub error: X,
+    pub suppressed: usize,
+}
+
+/// This is an observable that swallows the `Err` values identical to an `Err` value emitted within the last window. The `Ok` values and the terminated events pass through.
+pub struct SuppressRepeatedErrors<X, O, S> {
+    source: O,
+    window: Duration,
+    scheduler: Arc<S>,
+    _marker: PhantomData<X>,
+}
+
+impl<X, O, S> SuppressRepeatedErrors<X, O, S> {
+    pub fn new(source: O, window: Duration, scheduler: S) -> SuppressRepeatedErrors<X, O, S> {
+        SuppressRepeatedErrors {
+            source,
+            window,
+            scheduler: Arc::new(scheduler),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<X, O, S> Clone for SuppressRepeatedErrors<X, O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        SuppressRepeatedErrors {
+            source: self.source.clone(),
+            window: self.window,
+            scheduler: self.scheduler.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct MutedError<X> {
+    error: X,
+    timer_id: u64,
+    muted: bool,
+    suppressed: usize,
+}
+
+struct SuppressState<X> {
+    next_timer_id: u64,
+    errors: Vec<MutedError<X>>,
+    timers: HashMap<u64, Disposal<Box<dyn FnOnce() + Send>>>,
+}
+
+impl<T, X, E, O, S> Observable<Result<T, RepeatedError<X>>, E> for SuppressRepeatedErrors<X, O, S>
+where
+    O: Observable<Result<T, X>, E>,
+    X: Clone + PartialEq + Sync + Send + 'static,
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<Result<T, RepeatedError<X>>, E>) -> Subscription {
+        let scheduler = self.scheduler.clone();
+        let window = self.window;
+        let state = Arc::new(Mutex::new(SuppressState {
+            next_timer_id: 0,
+            errors: Vec::new(),
+            timers: HashMap::new(),
+        }));
+        let state_cloned = state.clone();
+        let observer = AnonymousObserver::new(move |event: Event<Result<T, X>, E>| {
+            let error = match event {
+                Event::Next(Ok(value)) => {
+                    observer.notify_if_unterminated(Event::Next(Ok(value)));
+                    return;
+                }
+                Event::Next(Err(error)) => error,
+                Event::Terminated(terminated) => {
+                    observer.notify_if_unterminated(Event::Terminated(terminated));
+                    return;
+                }
+            };
+            let (timer_id, suppressed) = {
+                let mut state = state.lock().unwrap();
+                let timer_id = state.next_timer_id;
+                let entry = state.errors.iter_mut().find(|entry| entry.error == error);
+                let suppressed = match entry {
+                    Some(entry) if entry.muted => {
+                        entry.suppressed += 1;
+                        return;
+                    }
+                    Some(entry) => {
+                        entry.muted = true;
+                        entry.timer_id = timer_id;
+                        std::mem::take(&mut entry.suppressed)
+                    }
+                    None => {
+                        state.errors.push(MutedError {
+                            error: error.clone(),
+                            timer_id,
+                            muted: true,
+                            suppressed: 0,
+                        });
+                        0
+                    }
+                };
+                state.next_timer_id += 1;
+                (timer_id, suppressed)
+            };
+            let state_for_timer = state.clone();
+            let timer = scheduler.schedule(
+                move || {
+                    let mut state = state_for_timer.lock().unwrap();
+                    if let Some(entry) = state
+                        .errors
+                        .iter_mut()
+                        .find(|entry| entry.timer_id == timer_id)
+                    {
+                        entry.muted = false;
+                    }
+                    state
+                        .errors
+                        .retain(|entry| entry.muted || entry.suppressed > 0);
+                    let timer = state.timers.remove(&timer_id);
+                    drop(state);
+                    drop(timer);
+                },
+                Some(window),
+            );
+            let timer = timer.to_boxed();
+            let mut state_guard = state.lock().unwrap();
+            if state_guard
+                .errors
+                .iter()
+                .any(|entry| entry.timer_id == timer_id && entry.muted)
+            {
+                state_guard.timers.insert(timer_id, timer);
+                drop(state_guard);
+            } else {
+                // The timer has already run.
+                drop(state_guard);
+                drop(timer);
+            }
+            observer.notify_if_unterminated(Event::Next(Err(RepeatedError { error, suppressed })));
+        });
+        let subscription = self.source.subscribe(observer);
+        subscription.insert_disposal_action(move || {
+            let timers: Vec<_> = state_cloned
+                .lock()
+                .unwrap()
+                .timers
+                .drain()
+                .map(|(_, timer)| timer)
+                .collect();
+            drop(timers);
+        })
+    }
+}
+
+/// Make the `Observable` of `Result` values suppress the repeated errors.
+pub trait SuppressRepeatedErrorsObservable<T, X, E> {
+    /**
+    Swallows the `Err` values identical to an `Err` value emitted within the last window. The next time the error is emitted, it carries the number of identical errors swallowed in between.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::suppress_repeated_errors::SuppressRepeatedErrorsObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new(Result::<i32, String>::Err("timeout".to_owned()));
+        let observable = observable.suppress_repeated_errors(Duration::from_secs(60), TokioScheduler::new());
+        observable.subscribe_on_next(|value| {
+            if let Err(error) = value {
+                println!("{} (suppressed {} times)", error.error, error.suppressed);
+            }
+        });
+    }
+    ```
+     */
+    fn suppress_repeated_errors<S>(
+        self,
+        window: Duration,
+        scheduler: S,
+    ) -> impl Observable<Result<T, RepeatedError<X>>, E>
+    where
+        X: Clone + PartialEq + Sync + Send + 'static,
+        S: Scheduler;
+}
+
+impl<O, T, X, E> SuppressRepeatedErrorsObservable<T, X, E> for O
+where
+    O: Observable<Result<T, X>, E>,
+{
+    fn suppress_repeated_errors<S>(
+        self,
+        window: Duration,
+        scheduler: S,
+    ) -> impl Observable<Result<T, RepeatedError<X>>, E>
+    where
+        X: Clone + PartialEq + Sync + Send + 'static,
+        S: Scheduler,
+    {
+        SuppressRepeatedErrors::new(self, window, scheduler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+
+    fn repeated(
+        error: &'static str,
+        suppressed: usize,
+    ) -> Result<i32, RepeatedError<&'static str>> {
+        Err(RepeatedError { error, suppressed })
+    }
+
+    #[test]
+    fn test_suppressed_within_window() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (0, Event::Next(Err("a"))),
+                (1, Event::Next(Err("a"))),
+                (2, Event::Next(Ok(1))),
+                (3, Event::Next(Err("b"))),
+                (4, Event::Next(Err("a"))),
+                (20, Event::Next(Err("a"))),
+                (21, Event::Next(Err("b"))),
+                (40, Event::Terminated(Terminated::Completed)),
+            ],
+        );
+        let observable =
+            observable.suppress_repeated_errors(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[
+            repeated("a", 0),
+            Ok(1),
+            repeated("b", 0),
+            repeated("a", 2),
+            repeated("b", 0),
+        ]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (0, Event::Next(Err("a"))),
+                (1, Event::Terminated(Terminated::Error("error".to_owned()))),
+            ],
+        );
+        let observable =
+            observable.suppress_repeated_errors(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[repeated("a", 0)]));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(scheduler.clone(), vec![(0, Event::Next(Err("a")))]);
+        let observable =
+            observable.suppress_repeated_errors(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        assert_eq!(scheduler.pending(), 1);
+        subscription.unsubscribe();
+        assert_eq!(scheduler.pending(), 0);
+        assert!(checker.is_values_matched(&[repeated("a", 0)]));
+        assert!(checker.is_unsubscribed());
+    }
+}
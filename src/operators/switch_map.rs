@@ -0,0 +1,357 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that maps each value of the source observable to an inner observable, and unsubscribes from the previous inner observable when a new value arrives, so only the latest inner observable's values are forwarded. It completes when the source and the latest inner observable have completed.
+pub struct SwitchMap<T, O, F> {
+    source: O,
+    mapper: Arc<F>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, O, F> SwitchMap<T, O, F> {
+    pub fn new(source: O, mapper: F) -> SwitchMap<T, O, F> {
+        SwitchMap {
+            source,
+            mapper: Arc::new(mapper),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, O, F> Clone for SwitchMap<T, O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        SwitchMap {
+            source: self.source.clone(),
+            mapper: self.mapper.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct SwitchMapState {
+    stopped: bool,
+    source_completed: bool,
+    next_id: u64,
+    /// The id of the latest inner observable and its subscription, `None` while it is being subscribed.
+    current: Option<(u64, Option<Subscription>)>,
+    source_subscription: Option<Subscription>,
+}
+
+impl SwitchMapState {
+    fn is_current(&self, id: u64) -> bool {
+        !self.stopped && matches!(self.current, Some((current, _)) if current == id)
+    }
+
+    /// Stop and take every subscription, so they can be dropped outside the lock.
+    fn stop(&mut self) -> Vec<Subscription> {
+        self.stopped = true;
+        let mut subscriptions: Vec<Subscription> = self
+            .current
+            .take()
+            .and_then(|(_, subscription)| subscription)
+            .into_iter()
+            .collect();
+        subscriptions.extend(self.source_subscription.take());
+        subscriptions
+    }
+}
+
+struct Switching<T, T2, E, F, OR> {
+    mapper: Arc<F>,
+    observer: Arc<OR>,
+    state: Mutex<SwitchMapState>,
+    _marker: PhantomData<fn(T) -> (T2, E)>,
+}
+
+impl<T, T2, E, F, O2, OR> Switching<T, T2, E, F, OR>
+where
+    T: Sync + Send + 'static,
+    T2: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+    F: Fn(T) -> O2 + Sync + Send + 'static,
+    O2: Observable<T2, E>,
+    OR: Observer<T2, E>,
+{
+    fn on_next(self: &Arc<Self>, value: T) {
+        let inner = (self.mapper)(value);
+        let (id, previous) = {
+            let mut state = self.state.lock().unwrap();
+            if state.stopped {
+                return;
+            }
+            let id = state.next_id;
+            state.next_id += 1;
+            let previous = state.current.replace((id, None));
+            (id, previous.and_then(|(_, subscription)| subscription))
+        };
+        drop(previous);
+        let switching = self.clone();
+        let inner_observer = AnonymousObserver::new(move |event: Event<T2, E>| match event {
+            Event::Next(_) => {
+                if switching.state.lock().unwrap().is_current(id) {
+                    switching.observer.notify_if_unterminated(event);
+                }
+            }
+            Event::Terminated(Terminated::Completed) => switching.on_inner_completed(id),
+            Event::Terminated(Terminated::Error(_)) => {
+                if switching.state.lock().unwrap().is_current(id) {
+                    switching.finish(event);
+                }
+            }
+            Event::Terminated(Terminated::Unsubscribed) => {}
+        });
+        let subscription = inner.subscribe(inner_observer);
+        let mut state = self.state.lock().unwrap();
+        match &mut state.current {
+            Some((current, slot)) if *current == id => *slot = Some(subscription),
+            _ => {
+                // The inner observable has already completed or been switched away, or everything has stopped.
+                drop(state);
+                drop(subscription);
+            }
+        }
+    }
+
+    fn on_inner_completed(&self, id: u64) {
+        let mut state = self.state.lock().unwrap();
+        if !state.is_current(id) {
+            return;
+        }
+        let subscription = state
+            .current
+            .take()
+            .and_then(|(_, subscription)| subscription);
+        let finished = state.source_completed;
+        drop(state);
+        drop(subscription);
+        if finished {
+            self.finish(Event::Terminated(Terminated::Completed));
+        }
+    }
+
+    fn on_source_completed(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.source_completed = true;
+        let finished = state.current.is_none();
+        drop(state);
+        if finished {
+            self.finish(Event::Terminated(Terminated::Completed));
+        }
+    }
+
+    fn finish(&self, event: Event<T2, E>) {
+        let subscriptions = self.state.lock().unwrap().stop();
+        self.observer.notify_if_unterminated(event);
+        drop(subscriptions);
+    }
+}
+
+impl<T, E, O, F, T2, O2> Observable<T2, E> for SwitchMap<T, O, F>
+where
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+    T2: Sync + Send + 'static,
+    O: Observable<T, E>,
+    F: Fn(T) -> O2 + Sync + Send + 'static,
+    O2: Observable<T2, E>,
+{
+    fn subscribe(self, observer: impl Observer<T2, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let switching = Arc::new(Switching {
+            mapper: self.mapper,
+            observer: observer.clone(),
+            state: Mutex::new(SwitchMapState {
+                stopped: false,
+                source_completed: false,
+                next_id: 0,
+                current: None,
+                source_subscription: None,
+            }),
+            _marker: PhantomData,
+        });
+        let switching_cloned = switching.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => switching_cloned.on_next(value),
+            Event::Terminated(Terminated::Completed) => switching_cloned.on_source_completed(),
+            Event::Terminated(Terminated::Error(error)) => {
+                switching_cloned.finish(Event::Terminated(Terminated::Error(error)))
+            }
+            Event::Terminated(Terminated::Unsubscribed) => {}
+        });
+        let subscription = self.source.subscribe(source_observer);
+        {
+            let mut state = switching.state.lock().unwrap();
+            if state.stopped {
+                drop(state);
+                drop(subscription);
+            } else {
+                state.source_subscription = Some(subscription);
+            }
+        }
+        Subscription::new(observer, move || {
+            let subscriptions = switching.state.lock().unwrap().stop();
+            drop(subscriptions);
+        })
+    }
+}
+
+/// Make the `Observable` switch-mappable.
+pub trait SwitchMapObservable<T, E> {
+    /**
+    Maps each value of the source observable to an inner observable, and unsubscribes from the previous inner observable when a new value arrives, so only the latest inner observable's values are forwarded.
+    It completes when the source and the latest inner observable have completed, and errors as soon as the source or the latest inner observable errors.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::switch_map::SwitchMapObservable;
+    use rx_rust::operators::range::Range;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.switch_map(|value| Range::new(0, value));
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn switch_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static;
+}
+
+impl<O, T, E> SwitchMapObservable<T, E> for O
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn switch_map<T2, O2>(
+        self,
+        f: impl Fn(T) -> O2 + Sync + Send + 'static,
+    ) -> impl Observable<T2, E>
+    where
+        O2: Observable<T2, E>,
+        T2: Sync + Send + 'static,
+    {
+        SwitchMap::new(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{
+            create::Create, delay::DelayableObservable, from_iter::FromIter, interval::Interval,
+            just::Just, map::MappableObservable, throw::Throw,
+        },
+        scheduler::{queue_scheduler::QueueScheduler, Scheduler},
+        utils::checking_observer::CheckingObserver,
+    };
+    use std::{convert::Infallible, time::Duration};
+
+    #[test]
+    fn test_synchronous_inners() {
+        let checker = CheckingObserver::new();
+        FromIter::new([1, 2, 3])
+            .switch_map(|value| FromIter::new([value * 10, value * 10 + 1]))
+            .subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[10, 11, 20, 21, 30, 31]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_latest_wins() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        // Emits 0, 1, 2 at 0ms, 25ms and 50ms, then completes.
+        let source = Create::new(move |observer: Box<dyn Observer<u64, Infallible>>| {
+            let observer = Arc::new(observer);
+            let disposals: Vec<_> = (0..4)
+                .map(|index| {
+                    let observer = observer.clone();
+                    scheduler_cloned.schedule(
+                        move || {
+                            let event = if index < 3 {
+                                Event::Next(index)
+                            } else {
+                                Event::Terminated(Terminated::Completed)
+                            };
+                            observer.notify_if_unterminated(event)
+                        },
+                        Some(Duration::from_millis(index * 25)),
+                    )
+                })
+                .collect();
+            Subscription::new(observer, move || drop(disposals))
+        });
+        let scheduler_cloned = scheduler.clone();
+        let observable = source.switch_map(move |value| {
+            Interval::new(Duration::from_millis(10), scheduler_cloned.clone())
+                .map(move |tick| format!("{}-{}", value, tick))
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        while scheduler.now() < Duration::from_millis(60) {
+            scheduler.run_one();
+        }
+        assert!(checker.is_values_matched(&[
+            "0-0".to_owned(),
+            "0-1".to_owned(),
+            "1-0".to_owned(),
+            "1-1".to_owned(),
+            "2-0".to_owned(),
+        ]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_waits_for_latest_inner() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let observable = FromIter::new([1, 2]).switch_map(move |value| {
+            Just::new(value).delay(Duration::from_millis(10), scheduler_cloned.clone())
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[2]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_inner_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .switch_map(|value| Throw::new(format!("error {}", value)));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_error("error 1".to_owned()));
+    }
+}
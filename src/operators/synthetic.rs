@@ -0,0 +1,270 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    collections::hash_map::DefaultHasher,
+    convert::Infallible,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/**
+Create a delay distribution for `Synthetic` that spreads the delays uniformly between `min` and `max`. The delays are pseudo-random but depend only on `seed` and the index, so a run can be reproduced.
+
+# Example
+```rust
+use rx_rust::operators::synthetic::uniform_jitter;
+use std::time::Duration;
+let delay = uniform_jitter(Duration::from_millis(10), Duration::from_millis(50), 42);
+assert!(delay(0) >= Duration::from_millis(10));
+assert!(delay(0) <= Duration::from_millis(50));
+assert_eq!(delay(7), delay(7));
+```
+*/
+pub fn uniform_jitter(
+    min: Duration,
+    max: Duration,
+    seed: u64,
+) -> impl Fn(usize) -> Duration + Sync + Send + 'static {
+    assert!(
+        min <= max,
+        "the min delay of uniform_jitter must not exceed the max delay"
+    );
+    move |index| {
+        let mut hasher = DefaultHasher::new();
+        (seed, index).hash(&mut hasher);
+        let fraction = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+        min + (max - min).mul_f64(fraction)
+    }
+}
+
+/**
+This is an observable that emits generated values at the intervals given by a delay distribution, for load testing and demos without an external feed.
+`delay(index)` is the wait before the value at `index`, and `generator(index)` creates it. It emits forever unless `limit` is set.
+
+# Example
+```rust
+use rx_rust::operators::synthetic::{uniform_jitter, Synthetic};
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+use std::time::Duration;
+let scheduler = QueueScheduler::new();
+let observable = Synthetic::new(
+    uniform_jitter(Duration::from_millis(10), Duration::from_millis(50), 42),
+    |index| format!("order-{}", index),
+    scheduler.clone(),
+)
+.limit(3);
+let subscription = observable.subscribe_on_next(|order| println!("{}", order));
+scheduler.run_until_idle();
+```
+*/
+pub struct Synthetic<D, G, S> {
+    delay: Arc<D>,
+    generator: Arc<G>,
+    scheduler: Arc<S>,
+    limit: Option<usize>,
+}
+
+impl<D, G, S> Synthetic<D, G, S> {
+    pub fn new(delay: D, generator: G, scheduler: S) -> Synthetic<D, G, S> {
+        Synthetic {
+            delay: Arc::new(delay),
+            generator: Arc::new(generator),
+            scheduler: Arc::new(scheduler),
+            limit: None,
+        }
+    }
+
+    /// Complete after emitting `count` values.
+    pub fn limit(mut self, count: usize) -> Synthetic<D, G, S> {
+        self.limit = Some(count);
+        self
+    }
+}
+
+impl<D, G, S> Clone for Synthetic<D, G, S> {
+    fn clone(&self) -> Self {
+        Synthetic {
+            delay: self.delay.clone(),
+            generator: self.generator.clone(),
+            scheduler: self.scheduler.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+struct SyntheticState {
+    stopped: bool,
+    index: usize,
+    timer: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+}
+
+struct Emitting<D, G, S, OR> {
+    synthetic: Synthetic<D, G, S>,
+    observer: Arc<OR>,
+    state: Mutex<SyntheticState>,
+}
+
+impl<T, D, G, S, OR> Emitting<D, G, S, OR>
+where
+    D: Fn(usize) -> Duration + Sync + Send + 'static,
+    G: Fn(usize) -> T + Sync + Send + 'static,
+    S: Scheduler,
+    OR: Observer<T, Infallible>,
+{
+    fn schedule(self: &Arc<Self>, index: usize) {
+        let emitting = self.clone();
+        let timer = self.synthetic.scheduler.schedule(
+            move || {
+                if emitting.state.lock().unwrap().stopped {
+                    return;
+                }
+                let value = (emitting.synthetic.generator)(index);
+                emitting.observer.notify_if_unterminated(Event::Next(value));
+                if emitting.synthetic.limit == Some(index + 1) {
+                    emitting.finish();
+                } else {
+                    emitting.schedule(index + 1);
+                }
+            },
+            Some((self.synthetic.delay)(index)),
+        );
+        let timer = timer.to_boxed();
+        let mut state = self.state.lock().unwrap();
+        let previous_timer = if !state.stopped && state.index <= index {
+            state.index = index;
+            state.timer.replace(timer)
+        } else {
+            // Unsubscribed, or the timer has already run and scheduled the next one.
+            Some(timer)
+        };
+        drop(state);
+        drop(previous_timer);
+    }
+
+    fn finish(&self) {
+        let timer = {
+            let mut state = self.state.lock().unwrap();
+            state.stopped = true;
+            state.timer.take()
+        };
+        self.observer
+            .notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        drop(timer);
+    }
+}
+
+impl<T, D, G, S> Observable<T, Infallible> for Synthetic<D, G, S>
+where
+    D: Fn(usize) -> Duration + Sync + Send + 'static,
+    G: Fn(usize) -> T + Sync + Send + 'static,
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<T, Infallible>) -> Subscription {
+        let observer = Arc::new(observer);
+        let emitting = Arc::new(Emitting {
+            synthetic: self,
+            observer: observer.clone(),
+            state: Mutex::new(SyntheticState {
+                stopped: false,
+                index: 0,
+                timer: None,
+            }),
+        });
+        if emitting.synthetic.limit == Some(0) {
+            emitting.finish();
+        } else {
+            emitting.schedule(0);
+        }
+        Subscription::new(observer, move || {
+            let timer = {
+                let mut state = emitting.state.lock().unwrap();
+                state.stopped = true;
+                state.timer.take()
+            };
+            drop(timer);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler, utils::checking_observer::CheckingObserver,
+    };
+
+    fn synthetic(
+        scheduler: &QueueScheduler,
+    ) -> Synthetic<
+        impl Fn(usize) -> Duration + Sync + Send + 'static,
+        impl Fn(usize) -> usize + Sync + Send + 'static,
+        QueueScheduler,
+    > {
+        Synthetic::new(
+            |index| Duration::from_millis(10 * (index as u64 + 1)),
+            |index| index * 2,
+            scheduler.clone(),
+        )
+    }
+
+    #[test]
+    fn test_limit() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription = synthetic(&scheduler).limit(3).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        scheduler.run_one();
+        assert_eq!(scheduler.now(), Duration::from_millis(10));
+        assert!(checker.is_values_matched(&[0]));
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(60));
+        assert!(checker.is_values_matched(&[0, 2, 4]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_zero_limit() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        synthetic(&scheduler).limit(0).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription = synthetic(&scheduler).subscribe(checker.clone());
+        scheduler.run_one();
+        scheduler.run_one();
+        assert!(checker.is_values_matched(&[0, 2]));
+        assert!(checker.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+
+    #[test]
+    fn test_uniform_jitter() {
+        let min = Duration::from_millis(10);
+        let max = Duration::from_millis(20);
+        let delay = uniform_jitter(min, max, 1);
+        let delays: Vec<Duration> = (0..100).map(&delay).collect();
+        assert!(delays.iter().all(|delay| (min..=max).contains(delay)));
+        assert!(delays.iter().any(|delay| *delay != delays[0]));
+        assert_eq!(delays, (0..100).map(delay).collect::<Vec<_>>());
+    }
+}
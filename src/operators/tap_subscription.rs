@@ -0,0 +1,260 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::sync::{Arc, Mutex};
+
+struct HandleState {
+    cancelled: bool,
+    subscription: Option<Subscription>,
+}
+
+/**
+A handle that can unsubscribe a subscription from outside the chain. It's given to the closure of `tap_subscription` for each subscription.
+
+# Example
+```rust
+use rx_rust::operators::create::Create;
+use rx_rust::operators::tap_subscription::TapSubscriptionObservable;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::observer::Observer;
+use rx_rust::subscription::Subscription;
+use std::sync::{Arc, Mutex};
+let handles = Arc::new(Mutex::new(Vec::new()));
+let handles_cloned = handles.clone();
+let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+    Subscription::new_non_disposal_action(observer)
+});
+let observable = observable.tap_subscription(move |handle| handles_cloned.lock().unwrap().push(handle));
+let subscription = observable.subscribe_on_event(|event| println!("{:?}", event));
+for handle in handles.lock().unwrap().drain(..) {
+    handle.unsubscribe();
+}
+```
+*/
+#[derive(Clone)]
+pub struct SubscriptionHandle {
+    state: Arc<Mutex<HandleState>>,
+}
+
+impl SubscriptionHandle {
+    fn new() -> SubscriptionHandle {
+        SubscriptionHandle {
+            state: Arc::new(Mutex::new(HandleState {
+                cancelled: false,
+                subscription: None,
+            })),
+        }
+    }
+
+    /// Unsubscribe the subscription. The observer receives the unsubscribed event unless it's already terminated.
+    pub fn unsubscribe(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.cancelled = true;
+        let subscription = state.subscription.take();
+        drop(state);
+        drop(subscription);
+    }
+
+    /// Get whether `unsubscribe` has been called on this handle or one of its clones.
+    pub fn is_unsubscribed(&self) -> bool {
+        self.state.lock().unwrap().cancelled
+    }
+
+    fn set_subscription(&self, subscription: Subscription) {
+        let mut state = self.state.lock().unwrap();
+        if state.cancelled {
+            drop(state);
+            drop(subscription);
+        } else {
+            state.subscription = Some(subscription);
+        }
+    }
+}
+
+/// This is an observable that forwards the source observable, and passes a `SubscriptionHandle` of each subscription to the closure before subscribing to the source.
+pub struct TapSubscription<O, F> {
+    source: O,
+    callback: Arc<F>,
+}
+
+impl<O, F> TapSubscription<O, F> {
+    pub fn new(source: O, callback: F) -> TapSubscription<O, F> {
+        TapSubscription {
+            source,
+            callback: Arc::new(callback),
+        }
+    }
+}
+
+impl<O, F> Clone for TapSubscription<O, F>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        TapSubscription {
+            source: self.source.clone(),
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+impl<T, E, O, F> Observable<T, E> for TapSubscription<O, F>
+where
+    O: Observable<T, E>,
+    F: Fn(SubscriptionHandle) + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let handle = SubscriptionHandle::new();
+        (self.callback)(handle.clone());
+        let observer = Arc::new(observer);
+        let observer_cloned = observer.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            observer_cloned.notify_if_unterminated(event)
+        });
+        handle.set_subscription(self.source.subscribe(source_observer));
+        Subscription::new(observer, move || handle.unsubscribe())
+    }
+}
+
+/// Make the `Observable` expose the handles of its subscriptions.
+pub trait TapSubscriptionObservable<T, E> {
+    /**
+    Calls `callback` with a `SubscriptionHandle` for each subscription, before subscribing to the source. The handle can be stored, e.g. in a registry, and used later to unsubscribe without holding the `Subscription`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::tap_subscription::TapSubscriptionObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.tap_subscription(|handle| {
+        println!("unsubscribed: {}", handle.is_unsubscribed());
+    });
+    observable.subscribe_on_next(|value| {
+        println!("{}", value);
+    });
+    ```
+     */
+    fn tap_subscription(
+        self,
+        callback: impl Fn(SubscriptionHandle) + Sync + Send + 'static,
+    ) -> impl Observable<T, E>;
+}
+
+impl<O, T, E> TapSubscriptionObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn tap_subscription(
+        self,
+        callback: impl Fn(SubscriptionHandle) + Sync + Send + 'static,
+    ) -> impl Observable<T, E> {
+        TapSubscription::new(self, callback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated, operators::create::Create,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    type Observers = Arc<Mutex<Vec<Arc<Box<dyn Observer<i32, String>>>>>>;
+
+    fn source() -> (impl Observable<i32, String>, Observers) {
+        let observers = Arc::new(Mutex::new(Vec::new()));
+        let observers_cloned = observers.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            let observer = Arc::new(observer);
+            observers_cloned.lock().unwrap().push(observer.clone());
+            Subscription::new_non_disposal_action(observer)
+        });
+        (observable, observers)
+    }
+
+    fn tapped(
+        observable: impl Observable<i32, String>,
+    ) -> (
+        impl Observable<i32, String>,
+        Arc<Mutex<Vec<SubscriptionHandle>>>,
+    ) {
+        let handles = Arc::new(Mutex::new(Vec::new()));
+        let handles_cloned = handles.clone();
+        let observable =
+            observable.tap_subscription(move |handle| handles_cloned.lock().unwrap().push(handle));
+        (observable, handles)
+    }
+
+    #[test]
+    fn test_unsubscribe_by_handle() {
+        let (observable, observers) = source();
+        let (observable, handles) = tapped(observable);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        observers.lock().unwrap()[0].notify_if_unterminated(Event::Next(1));
+        let handle = handles.lock().unwrap()[0].clone();
+        assert!(!handle.is_unsubscribed());
+        handle.unsubscribe();
+        assert!(handle.is_unsubscribed());
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_unsubscribed());
+        assert!(observers.lock().unwrap()[0].terminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe_in_callback() {
+        let (observable, observers) = source();
+        let observable = observable.tap_subscription(|handle| handle.unsubscribe());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_unsubscribed());
+        assert!(observers.lock().unwrap()[0].terminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_handle_per_subscription() {
+        let (observable, _) = source();
+        let (observable, handles) = tapped(observable);
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        let checker2 = CheckingObserver::new();
+        let subscription2 = observable.subscribe(checker2.clone());
+        handles.lock().unwrap()[0].unsubscribe();
+        assert!(checker1.is_unsubscribed());
+        assert!(checker2.is_unterminated());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+
+    #[test]
+    fn test_completed() {
+        let (observable, handles) =
+            tapped(Create::new(|observer: Box<dyn Observer<i32, String>>| {
+                observer.notify_if_unterminated(Event::Next(333));
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                Subscription::new_non_disposal_action(observer)
+            }));
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        handles.lock().unwrap()[0].unsubscribe();
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let (observable, observers) = source();
+        let (observable, handles) = tapped(observable);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(handles.lock().unwrap()[0].is_unsubscribed());
+        assert!(checker.is_unsubscribed());
+        assert!(observers.lock().unwrap()[0].terminated());
+    }
+}
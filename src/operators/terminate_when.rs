@@ -0,0 +1,249 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that forwards the source observable until the signal observable emits a value, then it terminates with the `Terminated` returned by the closure for that value.
+pub struct TerminateWhen<S, SE, O, OS, F> {
+    source: O,
+    signal: OS,
+    terminated: Arc<F>,
+    _marker: PhantomData<(S, SE)>,
+}
+
+impl<S, SE, O, OS, F> TerminateWhen<S, SE, O, OS, F> {
+    pub fn new(source: O, signal: OS, terminated: F) -> TerminateWhen<S, SE, O, OS, F> {
+        TerminateWhen {
+            source,
+            signal,
+            terminated: Arc::new(terminated),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, SE, O, OS, F> Clone for TerminateWhen<S, SE, O, OS, F>
+where
+    O: Clone,
+    OS: Clone,
+{
+    fn clone(&self) -> Self {
+        TerminateWhen {
+            source: self.source.clone(),
+            signal: self.signal.clone(),
+            terminated: self.terminated.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[derive(Default)]
+struct TerminateWhenSubscriptions {
+    source: Option<Subscription>,
+    signal: Option<Subscription>,
+}
+
+impl<T, E, S, SE, O, OS, F> Observable<T, E> for TerminateWhen<S, SE, O, OS, F>
+where
+    O: Observable<T, E>,
+    OS: Observable<S, SE>,
+    F: Fn(S) -> Terminated<E> + Sync + Send + 'static,
+    S: Sync + Send + 'static,
+    SE: Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let subscriptions = Arc::new(Mutex::new(TerminateWhenSubscriptions::default()));
+
+        let terminated = self.terminated.clone();
+        let observer_cloned = observer.clone();
+        let subscriptions_cloned = subscriptions.clone();
+        let signal_observer = AnonymousObserver::new(move |event: Event<S, SE>| {
+            if let Event::Next(value) = event {
+                observer_cloned.notify_if_unterminated(Event::Terminated(terminated(value)));
+                let subscriptions = std::mem::take(&mut *subscriptions_cloned.lock().unwrap());
+                drop(subscriptions);
+            }
+        });
+        let signal_subscription = self.signal.subscribe(signal_observer);
+        if observer.terminated() {
+            drop(signal_subscription);
+            return Subscription::new_non_disposal_action(observer);
+        }
+        subscriptions.lock().unwrap().signal = Some(signal_subscription);
+
+        let observer_cloned = observer.clone();
+        let subscriptions_cloned = subscriptions.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(_) => observer_cloned.notify_if_unterminated(event),
+            Event::Terminated(_) => {
+                observer_cloned.notify_if_unterminated(event);
+                let signal_subscription = subscriptions_cloned.lock().unwrap().signal.take();
+                drop(signal_subscription);
+            }
+        });
+        let source_subscription = self.source.subscribe(source_observer);
+        if observer.terminated() {
+            let signal_subscription = subscriptions.lock().unwrap().signal.take();
+            drop(signal_subscription);
+            drop(source_subscription);
+        } else {
+            subscriptions.lock().unwrap().source = Some(source_subscription);
+        }
+        Subscription::new(observer, move || {
+            let subscriptions = std::mem::take(&mut *subscriptions.lock().unwrap());
+            drop(subscriptions);
+        })
+    }
+}
+
+/// Make the `Observable` terminable by a signal.
+pub trait TerminateWhenObservable<T, E> {
+    /**
+    Forwards the values until `signal` emits a value, then terminates with the `Terminated` returned by `terminated` for that value. Both the source and the signal are unsubscribed.
+
+    # Example
+    ```rust
+    use rx_rust::operators::create::Create;
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::terminate_when::TerminateWhenObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::observer::event::Terminated;
+    use rx_rust::observer::Observer;
+    use rx_rust::subscription::Subscription;
+    let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+        Subscription::new_non_disposal_action(observer)
+    });
+    let shutdown = Just::new("maintenance");
+    let observable = observable.terminate_when(shutdown, |reason: &'static str| {
+        Terminated::Error(format!("shut down: {}", reason))
+    });
+    observable.subscribe_on_event(|event| {
+        println!("{:?}", event);
+    });
+    ```
+     */
+    fn terminate_when<S, SE>(
+        self,
+        signal: impl Observable<S, SE>,
+        terminated: impl Fn(S) -> Terminated<E> + Sync + Send + 'static,
+    ) -> impl Observable<T, E>
+    where
+        S: Sync + Send + 'static,
+        SE: Sync + Send + 'static;
+}
+
+impl<O, T, E> TerminateWhenObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn terminate_when<S, SE>(
+        self,
+        signal: impl Observable<S, SE>,
+        terminated: impl Fn(S) -> Terminated<E> + Sync + Send + 'static,
+    ) -> impl Observable<T, E>
+    where
+        S: Sync + Send + 'static,
+        SE: Sync + Send + 'static,
+    {
+        TerminateWhen::new(self, signal, terminated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    type Observers<T> = Arc<Mutex<Vec<Arc<Box<dyn Observer<T, String>>>>>>;
+
+    fn source<T: Sync + Send + 'static>() -> (impl Observable<T, String>, Observers<T>) {
+        let observers = Arc::new(Mutex::new(Vec::new()));
+        let observers_cloned = observers.clone();
+        let observable = Create::new(move |observer: Box<dyn Observer<T, String>>| {
+            let observer = Arc::new(observer);
+            observers_cloned.lock().unwrap().push(observer.clone());
+            Subscription::new_non_disposal_action(observer)
+        });
+        (observable, observers)
+    }
+
+    #[test]
+    fn test_signal_error() {
+        let (observable, observers) = source::<i32>();
+        let (signal, signals) = source::<&'static str>();
+        let observable = observable.terminate_when(signal, |reason| {
+            Terminated::Error(format!("shut down: {}", reason))
+        });
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        observers.lock().unwrap()[0].notify_if_unterminated(Event::Next(1));
+        signals.lock().unwrap()[0].notify_if_unterminated(Event::Next("maintenance"));
+        observers.lock().unwrap()[0].notify_if_unterminated(Event::Next(2));
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_error("shut down: maintenance".to_owned()));
+        assert!(observers.lock().unwrap()[0].terminated());
+        assert!(signals.lock().unwrap()[0].terminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_signal_completed() {
+        let (observable, _) = source::<i32>();
+        let observable = observable.terminate_when(Just::new(()), |_| Terminated::Completed);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_source_completed() {
+        let (signal, signals) = source::<()>();
+        let observable = Just::new(333).terminate_when(signal, |_| Terminated::Completed);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        assert!(signals.lock().unwrap()[0].terminated());
+    }
+
+    #[test]
+    fn test_signal_terminated_without_value() {
+        let (observable, observers) = source::<i32>();
+        let (signal, signals) = source::<()>();
+        let observable = observable.terminate_when(signal, |_| Terminated::Completed);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        signals.lock().unwrap()[0].notify_if_unterminated(Event::Terminated(Terminated::Completed));
+        observers.lock().unwrap()[0].notify_if_unterminated(Event::Next(1));
+        assert!(checker.is_values_matched(&[1]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let (observable, observers) = source::<i32>();
+        let (signal, signals) = source::<()>();
+        let observable = observable.terminate_when(signal, |_| Terminated::Completed);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(observers.lock().unwrap()[0].terminated());
+        assert!(signals.lock().unwrap()[0].terminated());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// This is an observable that pairs each value of the source observable with the time elapsed since the previous value, or since subscribing for the first value.
+#[derive(Clone)]
+pub struct TimeInterval<O> {
+    source: O,
+}
+
+impl<O> TimeInterval<O> {
+    pub fn new(source: O) -> TimeInterval<O> {
+        TimeInterval { source }
+    }
+}
+
+impl<T, E, O> Observable<(Duration, T), E> for TimeInterval<O>
+where
+    O: Observable<T, E>,
+{
+    fn subscribe(self, observer: impl Observer<(Duration, T), E>) -> Subscription {
+        let previous = Mutex::new(Instant::now());
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            observer.notify_if_unterminated(event.map_value(|value| {
+                let now = Instant::now();
+                let previous = std::mem::replace(&mut *previous.lock().unwrap(), now);
+                (now - previous, value)
+            }))
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` measure the intervals between its values.
+pub trait TimeIntervalObservable<T, E> {
+    /**
+    Pairs each value with the time elapsed since the previous value, or since subscribing for the first value, e.g. to measure the cadence of a source or find a slow producer. It uses the same clock as `stamp_age`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::time_interval::TimeIntervalObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=3);
+    let observable = observable.time_interval();
+    observable.subscribe_on_next(|(interval, value)| {
+        println!("{} after {:?}", value, interval);
+    });
+    ```
+     */
+    fn time_interval(self) -> impl Observable<(Duration, T), E>;
+}
+
+impl<O, T, E> TimeIntervalObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn time_interval(self) -> impl Observable<(Duration, T), E> {
+        TimeInterval::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::observable_subscribe_ext::ObservableSubscribeExt, observer::event::Terminated,
+        operators::create::Create,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn test_intervals() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            std::thread::sleep(Duration::from_millis(20));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer.notify_if_unterminated(Event::Next(3));
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        })
+        .time_interval();
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let started = Instant::now();
+        observable.collect_into(values.clone());
+        let elapsed = started.elapsed();
+        let values = values.lock().unwrap();
+        assert_eq!(
+            values.iter().map(|(_, value)| *value).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(values[0].0 < Duration::from_millis(20));
+        assert!(values[1].0 >= Duration::from_millis(20));
+        assert!(
+            values
+                .iter()
+                .map(|(interval, _)| *interval)
+                .sum::<Duration>()
+                <= elapsed
+        );
+    }
+}
@@ -0,0 +1,373 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// How long `timeout` waits for the values. The first value gets its own budget, so a slow connection does not require a long gap between the later values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutPolicy {
+    /// The longest time from subscribing to the first value.
+    pub first_item: Duration,
+    /// The longest time between two values. `None` means no limit once the first value has arrived.
+    pub each_item: Option<Duration>,
+}
+
+impl TimeoutPolicy {
+    pub fn new(first_item: Duration, each_item: Option<Duration>) -> TimeoutPolicy {
+        TimeoutPolicy {
+            first_item,
+            each_item,
+        }
+    }
+
+    /// Use the same duration for the first value and between the values.
+    pub fn fixed(duration: Duration) -> TimeoutPolicy {
+        TimeoutPolicy::new(duration, Some(duration))
+    }
+}
+
+/// The error of a `timeout` observable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutError<E> {
+    /// No value arrived in time.
+    Elapsed,
+    /// The source observable terminated with an error.
+    Source(E),
+}
+
+/// This is an observable that forwards the source observable, and terminates with `TimeoutError::Elapsed` if a value does not arrive within the budget of its `TimeoutPolicy`.
+pub struct Timeout<O, S> {
+    source: O,
+    policy: TimeoutPolicy,
+    scheduler: Arc<S>,
+}
+
+impl<O, S> Timeout<O, S> {
+    pub fn new(source: O, policy: TimeoutPolicy, scheduler: S) -> Timeout<O, S> {
+        Timeout {
+            source,
+            policy,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<O, S> Clone for Timeout<O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        Timeout {
+            source: self.source.clone(),
+            policy: self.policy,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct TimeoutState {
+    finished: bool,
+    received: bool,
+    generation: u64,
+    timer: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+    source_subscription: Option<Subscription>,
+}
+
+fn schedule_timer<T, E, S, OR>(
+    scheduler: &S,
+    state: &Arc<Mutex<TimeoutState>>,
+    observer: &Arc<OR>,
+    delay: Duration,
+    generation: u64,
+) where
+    S: Scheduler,
+    OR: Observer<T, TimeoutError<E>>,
+{
+    let state_for_timer = state.clone();
+    let observer = observer.clone();
+    let timer = scheduler.schedule(
+        move || {
+            let mut state = state_for_timer.lock().unwrap();
+            if state.finished || state.generation != generation {
+                return;
+            }
+            state.finished = true;
+            let timer = state.timer.take();
+            let source_subscription = state.source_subscription.take();
+            drop(state);
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                TimeoutError::Elapsed,
+            )));
+            drop(source_subscription);
+            drop(timer);
+        },
+        Some(delay),
+    );
+    let timer = timer.to_boxed();
+    let mut state = state.lock().unwrap();
+    let previous_timer = if !state.finished && state.generation == generation {
+        state.timer.replace(timer)
+    } else {
+        // The timer has already run, or a newer value has arrived.
+        Some(timer)
+    };
+    drop(state);
+    drop(previous_timer);
+}
+
+impl<T, E, O, S> Observable<T, TimeoutError<E>> for Timeout<O, S>
+where
+    O: Observable<T, E>,
+    S: Scheduler,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<T, TimeoutError<E>>) -> Subscription {
+        let scheduler = self.scheduler.clone();
+        let each_item = self.policy.each_item;
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(TimeoutState {
+            finished: false,
+            received: false,
+            generation: 0,
+            timer: None,
+            source_subscription: None,
+        }));
+        let state_cloned = state.clone();
+        let observer_cloned = observer.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| {
+            let mut state_guard = state_cloned.lock().unwrap();
+            if state_guard.finished {
+                return;
+            }
+            match event {
+                Event::Next(value) => {
+                    state_guard.received = true;
+                    state_guard.generation += 1;
+                    let generation = state_guard.generation;
+                    let timer = state_guard.timer.take();
+                    drop(state_guard);
+                    drop(timer);
+                    if let Some(each_item) = each_item {
+                        schedule_timer(
+                            scheduler.as_ref(),
+                            &state_cloned,
+                            &observer_cloned,
+                            each_item,
+                            generation,
+                        );
+                    }
+                    observer_cloned.notify_if_unterminated(Event::Next(value));
+                }
+                Event::Terminated(terminated) => {
+                    state_guard.finished = true;
+                    let timer = state_guard.timer.take();
+                    drop(state_guard);
+                    drop(timer);
+                    observer_cloned.notify_if_unterminated(
+                        Event::Terminated(terminated).map_error(TimeoutError::Source),
+                    );
+                }
+            }
+        });
+        let subscription = self.source.subscribe(source_observer);
+        let mut state_guard = state.lock().unwrap();
+        if state_guard.finished {
+            drop(state_guard);
+            drop(subscription);
+        } else {
+            state_guard.source_subscription = Some(subscription);
+            let received = state_guard.received;
+            drop(state_guard);
+            if !received {
+                schedule_timer(
+                    self.scheduler.as_ref(),
+                    &state,
+                    &observer,
+                    self.policy.first_item,
+                    0,
+                );
+            }
+        }
+        Subscription::new(observer, move || {
+            let mut state = state.lock().unwrap();
+            state.finished = true;
+            let timer = state.timer.take();
+            let source_subscription = state.source_subscription.take();
+            drop(state);
+            drop(timer);
+            drop(source_subscription);
+        })
+    }
+}
+
+/// Make the `Observable` fail when its values are late.
+pub trait TimeoutObservable<T, E> {
+    /**
+    Terminates with `TimeoutError::Elapsed` when the first value does not arrive within `policy.first_item`, or a later value does not arrive within `policy.each_item` of the previous one. The errors of the source are wrapped in `TimeoutError::Source`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::timeout::{TimeoutObservable, TimeoutPolicy};
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new(333);
+        let policy = TimeoutPolicy::new(Duration::from_secs(30), Some(Duration::from_secs(5)));
+        let observable = observable.timeout(policy, TokioScheduler::new());
+        observable.subscribe_on_event(|event| {
+            println!("{:?}", event);
+        });
+    }
+    ```
+     */
+    fn timeout<S>(self, policy: TimeoutPolicy, scheduler: S) -> impl Observable<T, TimeoutError<E>>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static;
+}
+
+impl<O, T, E> TimeoutObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn timeout<S>(self, policy: TimeoutPolicy, scheduler: S) -> impl Observable<T, TimeoutError<E>>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        Timeout::new(self, policy, scheduler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+
+    fn policy(first_item: u64, each_item: Option<u64>) -> TimeoutPolicy {
+        TimeoutPolicy::new(
+            Duration::from_millis(first_item),
+            each_item.map(Duration::from_millis),
+        )
+    }
+
+    #[test]
+    fn test_completed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (50, Event::Next(1)),
+                (60, Event::Next(2)),
+                (70, Event::Terminated(Terminated::Completed)),
+            ],
+        )
+        .timeout(policy(100, Some(20)), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.pending(), 0);
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_first_item_elapsed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(scheduler.clone(), vec![(50, Event::Next(1))])
+            .timeout(policy(30, Some(100)), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(30));
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error(TimeoutError::Elapsed));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_each_item_elapsed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (50, Event::Next(1)),
+                (60, Event::Next(2)),
+                (90, Event::Next(3)),
+            ],
+        )
+        .timeout(policy(100, Some(20)), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(80));
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_error(TimeoutError::Elapsed));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_no_each_item_limit() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![(10, Event::Next(1)), (1000, Event::Next(2))],
+        )
+        .timeout(policy(20, None), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[1, 2]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_source_error() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source::<i32>(
+            scheduler.clone(),
+            vec![(10, Event::Terminated(Terminated::Error("error".to_owned())))],
+        )
+        .timeout(policy(20, None), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_error(TimeoutError::Source("error".to_owned())));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(scheduler.clone(), vec![(10, Event::Next(1))])
+            .timeout(policy(20, None), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert_eq!(scheduler.pending(), 2);
+        subscription.unsubscribe();
+        assert_eq!(scheduler.pending(), 0);
+        assert!(checker.is_unsubscribed());
+    }
+}
@@ -0,0 +1,108 @@
+use super::synthetic::Synthetic;
+use crate::{
+    observable::Observable, observer::Observer, scheduler::Scheduler, subscription::Subscription,
+};
+use std::{convert::Infallible, sync::Arc, time::Duration};
+
+/**
+This is an observable that emits 0 on the scheduler after `delay`, then completes. The timer is cancelled when the subscription is unsubscribed or dropped before it fires.
+
+# Example
+```rust
+use rx_rust::operators::timer::Timer;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+use std::time::Duration;
+let scheduler = QueueScheduler::new();
+let observable = Timer::new(Duration::from_secs(1), scheduler.clone());
+let subscription = observable.subscribe_on_next(|_| println!("fired"));
+scheduler.run_until_idle();
+```
+*/
+pub struct Timer<S> {
+    delay: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<S> Timer<S> {
+    pub fn new(delay: Duration, scheduler: S) -> Timer<S> {
+        Timer {
+            delay,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<S> Clone for Timer<S> {
+    fn clone(&self) -> Self {
+        Timer {
+            delay: self.delay,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+impl<S> Observable<usize, Infallible> for Timer<S>
+where
+    S: Scheduler,
+{
+    fn subscribe(self, observer: impl Observer<usize, Infallible>) -> Subscription {
+        let delay = self.delay;
+        Synthetic::new(move |_| delay, |index| index, self.scheduler)
+            .limit(1)
+            .subscribe(observer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler, utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_completed() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription =
+            Timer::new(Duration::from_millis(10), scheduler.clone()).subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(10));
+        assert!(checker.is_values_matched(&[0]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let checker = CheckingObserver::new();
+        let subscription =
+            Timer::new(Duration::from_millis(10), scheduler.clone()).subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert_eq!(scheduler.pending(), 0);
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[]));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let scheduler = QueueScheduler::new();
+        let observable = Timer::new(Duration::from_millis(10), scheduler.clone());
+        let checker1 = CheckingObserver::new();
+        let subscription1 = observable.clone().subscribe(checker1.clone());
+        scheduler.run_until_idle();
+        let checker2 = CheckingObserver::new();
+        let subscription2 = observable.subscribe(checker2.clone());
+        scheduler.run_until_idle();
+        assert_eq!(scheduler.now(), Duration::from_millis(20));
+        assert!(checker1.is_completed());
+        assert!(checker2.is_values_matched(&[0]));
+        assert!(checker2.is_completed());
+        _ = (subscription1, subscription2); // keep the subscriptions alive
+    }
+}
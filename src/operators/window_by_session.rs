@@ -0,0 +1,258 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+    utils::disposal::Disposal,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// This is an observable that collects the values into sessions. A session starts on the first value after a quiet gap, and is emitted as a `Vec` once no value has arrived for the gap. The remaining session is emitted before the completed event.
+pub struct WindowBySession<O, S> {
+    source: O,
+    gap: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<O, S> WindowBySession<O, S> {
+    pub fn new(source: O, gap: Duration, scheduler: S) -> WindowBySession<O, S> {
+        WindowBySession {
+            source,
+            gap,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<O, S> Clone for WindowBySession<O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        WindowBySession {
+            source: self.source.clone(),
+            gap: self.gap,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct SessionState<T> {
+    session: Vec<T>,
+    generation: u64,
+    timer: Option<Disposal<Box<dyn FnOnce() + Send>>>,
+}
+
+impl<T, E, O, S> Observable<Vec<T>, E> for WindowBySession<O, S>
+where
+    O: Observable<T, E>,
+    S: Scheduler,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let scheduler = self.scheduler.clone();
+        let gap = self.gap;
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(SessionState {
+            session: Vec::new(),
+            generation: 0,
+            timer: None,
+        }));
+        let state_cloned = state.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let generation = {
+                    let mut state = state.lock().unwrap();
+                    state.session.push(value);
+                    state.generation += 1;
+                    state.generation
+                };
+                let state_for_timer = state.clone();
+                let observer = observer.clone();
+                let timer = scheduler.schedule(
+                    move || {
+                        let mut state = state_for_timer.lock().unwrap();
+                        if state.generation != generation {
+                            return;
+                        }
+                        let session = std::mem::take(&mut state.session);
+                        drop(state);
+                        if !session.is_empty() {
+                            observer.notify_if_unterminated(Event::Next(session));
+                        }
+                    },
+                    Some(gap),
+                );
+                let timer = timer.to_boxed();
+                let mut state = state.lock().unwrap();
+                let previous_timer = if state.generation == generation {
+                    state.timer.replace(timer)
+                } else {
+                    // A newer value has already replaced this timer.
+                    Some(timer)
+                };
+                drop(state);
+                drop(previous_timer);
+            }
+            Event::Terminated(terminated) => {
+                let mut state = state.lock().unwrap();
+                state.generation += 1;
+                let timer = state.timer.take();
+                let session = std::mem::take(&mut state.session);
+                drop(state);
+                drop(timer);
+                if let Terminated::Completed = terminated {
+                    if !session.is_empty() {
+                        observer.notify_if_unterminated(Event::Next(session));
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+        });
+        let subscription = self.source.subscribe(source_observer);
+        subscription.insert_disposal_action(move || {
+            let mut state = state_cloned.lock().unwrap();
+            state.generation += 1;
+            let timer = state.timer.take();
+            drop(state);
+            drop(timer);
+        })
+    }
+}
+
+/// Make the `Observable` windowable by session.
+pub trait WindowBySessionObservable<T, E> {
+    /**
+    Collects the values into sessions separated by quiet gaps, and emits each session as a `Vec`.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::window_by_session::WindowBySessionObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+    use std::time::Duration;
+    #[tokio::main]
+    async fn main() {
+        let observable = Just::new(333);
+        let observable = observable.window_by_session(Duration::from_secs(1), TokioScheduler::new());
+        observable.subscribe_on_event(|event| {
+            println!("{:?}", event);
+        });
+    }
+    ```
+     */
+    fn window_by_session<S>(self, gap: Duration, scheduler: S) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static;
+}
+
+impl<O, T, E> WindowBySessionObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn window_by_session<S>(self, gap: Duration, scheduler: S) -> impl Observable<Vec<T>, E>
+    where
+        S: Scheduler,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        WindowBySession::new(self, gap, scheduler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+
+    #[test]
+    fn test_sessions() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (0, Event::Next(1)),
+                (5, Event::Next(2)),
+                (20, Event::Next(3)),
+                (40, Event::Terminated(Terminated::Completed)),
+            ],
+        );
+        let observable = observable.window_by_session(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        while scheduler.now() < Duration::from_millis(15) && scheduler.run_one() {}
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+        assert!(checker.is_unterminated());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1, 2], vec![3]]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_flush_on_completed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (0, Event::Next(1)),
+                (5, Event::Terminated(Terminated::Completed)),
+            ],
+        );
+        let observable = observable.window_by_session(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[vec![1]]));
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(5));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(
+            scheduler.clone(),
+            vec![
+                (0, Event::Next(1)),
+                (5, Event::Terminated(Terminated::Error("error".to_owned()))),
+            ],
+        );
+        let observable = observable.window_by_session(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_until_idle();
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let scheduler = QueueScheduler::new();
+        let observable = timed_source(scheduler.clone(), vec![(0, Event::Next(1))]);
+        let observable = observable.window_by_session(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        scheduler.run_one();
+        subscription.unsubscribe();
+        assert_eq!(scheduler.run_until_idle(), 0);
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unsubscribed());
+    }
+}
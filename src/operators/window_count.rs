@@ -0,0 +1,217 @@
+use crate::{
+    observable::{
+        hot_observable::{HotEmitter, HotObservable},
+        Observable,
+    },
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::sync::Mutex;
+
+/// This is an observable that splits the values of the source observable into windows of `count` values, and emits each window as a `HotObservable` when its first value arrives. The window is completed after its last value, and terminated together with the source.
+#[derive(Clone)]
+pub struct WindowCount<O> {
+    source: O,
+    count: usize,
+}
+
+impl<O> WindowCount<O> {
+    /// Panics if `count` is 0.
+    pub fn new(source: O, count: usize) -> WindowCount<O> {
+        assert!(count > 0, "the count of window_count must be positive");
+        WindowCount { source, count }
+    }
+}
+
+struct WindowState<T, E> {
+    window: Option<HotEmitter<T, E>>,
+    received: usize,
+}
+
+impl<T, E, O> Observable<HotObservable<T, E>, E> for WindowCount<O>
+where
+    O: Observable<T, E>,
+    T: Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<HotObservable<T, E>, E>) -> Subscription {
+        let count = self.count;
+        let state = Mutex::new(WindowState {
+            window: None,
+            received: 0,
+        });
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state_guard = state.lock().unwrap();
+                let (window, opened) = match &state_guard.window {
+                    Some(window) => (window.clone(), None),
+                    None => {
+                        let (window, observable) = HotObservable::new();
+                        state_guard.window = Some(window.clone());
+                        state_guard.received = 0;
+                        (window, Some(observable))
+                    }
+                };
+                state_guard.received += 1;
+                let closed = state_guard.received == count;
+                if closed {
+                    state_guard.window = None;
+                }
+                drop(state_guard);
+                if let Some(observable) = opened {
+                    // Emitted before its first value, so the downstream can subscribe to it in time.
+                    observer.notify_if_unterminated(Event::Next(observable));
+                }
+                window.emit(value);
+                if closed {
+                    window.complete();
+                }
+            }
+            Event::Terminated(terminated) => {
+                let window = state.lock().unwrap().window.take();
+                if let Some(window) = window {
+                    match &terminated {
+                        Terminated::Error(error) => window.error(error.clone()),
+                        _ => window.complete(),
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` windowable by count.
+pub trait WindowCountObservable<T, E> {
+    /**
+    Splits the values into windows of `count` values, and emits each window as a `HotObservable` when its first value arrives, so each window can be processed as a stream instead of waiting for a full buffer. Subscribe to the window when it is emitted to receive all its values.
+    The window is completed after its last value, completed when the source completes or is unsubscribed, and receives the error when the source errors.
+
+    Panics if `count` is 0.
+
+    # Example
+    ```rust
+    use rx_rust::operators::from_iter::FromIter;
+    use rx_rust::operators::window_count::WindowCountObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = FromIter::new(1..=5);
+    let observable = observable.window_count(2);
+    observable.subscribe_on_next(|window| {
+        window.subscribe_on_next(|value| println!("{}", value));
+    });
+    ```
+     */
+    fn window_count(self, count: usize) -> impl Observable<HotObservable<T, E>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> WindowCountObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn window_count(self, count: usize) -> impl Observable<HotObservable<T, E>, E>
+    where
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+    {
+        WindowCount::new(self, count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{operators::create::Create, utils::checking_observer::CheckingObserver};
+    use std::sync::Arc;
+
+    fn source(values: Vec<i32>, end: Option<Terminated<String>>) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            if let Some(end) = end.clone() {
+                observer.notify_if_unterminated(Event::Terminated(end));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    /// The checker and the subscription of each window.
+    type Windows = Arc<Mutex<Vec<(CheckingObserver<i32, String>, Subscription)>>>;
+
+    /// Subscribe to the windows as they are emitted.
+    fn subscribe_windows(
+        observable: impl Observable<HotObservable<i32, String>, String>,
+    ) -> (
+        CheckingObserver<HotObservable<i32, String>, String>,
+        Windows,
+        Subscription,
+    ) {
+        let checker = CheckingObserver::new();
+        let windows = Arc::new(Mutex::new(Vec::new()));
+        let windows_cloned = windows.clone();
+        let checker_cloned = checker.clone();
+        let subscription = observable.subscribe(AnonymousObserver::new(
+            move |event: Event<HotObservable<i32, String>, String>| {
+                if let Event::Next(window) = &event {
+                    let window_checker = CheckingObserver::new();
+                    let window_subscription = window.clone().subscribe(window_checker.clone());
+                    windows_cloned
+                        .lock()
+                        .unwrap()
+                        .push((window_checker, window_subscription));
+                }
+                checker_cloned.notify_if_unterminated(event);
+            },
+        ));
+        (checker, windows, subscription)
+    }
+
+    #[test]
+    fn test_completed() {
+        let (checker, windows, subscription) = subscribe_windows(
+            source(vec![1, 2, 3, 4, 5], Some(Terminated::Completed)).window_count(2),
+        );
+        let windows = windows.lock().unwrap();
+        assert_eq!(windows.len(), 3);
+        assert!(windows[0].0.is_values_matched(&[1, 2]));
+        assert!(windows[0].0.is_completed());
+        assert!(windows[1].0.is_values_matched(&[3, 4]));
+        assert!(windows[1].0.is_completed());
+        assert!(windows[2].0.is_values_matched(&[5]));
+        assert!(windows[2].0.is_completed());
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let (checker, windows, subscription) = subscribe_windows(
+            source(vec![1, 2, 3], Some(Terminated::Error("error".to_owned()))).window_count(2),
+        );
+        let windows = windows.lock().unwrap();
+        assert_eq!(windows.len(), 2);
+        assert!(windows[0].0.is_completed());
+        assert!(windows[1].0.is_values_matched(&[3]));
+        assert!(windows[1].0.is_error("error".to_owned()));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let (checker, windows, subscription) =
+            subscribe_windows(source(vec![1], None).window_count(2));
+        assert!(windows.lock().unwrap()[0].0.is_unterminated());
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(windows.lock().unwrap()[0].0.is_completed());
+    }
+}
@@ -0,0 +1,291 @@
+use super::interval::Interval;
+use crate::{
+    observable::{
+        hot_observable::{HotEmitter, HotObservable},
+        Observable,
+    },
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    scheduler::Scheduler,
+    subscription::Subscription,
+};
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// This is an observable that splits the values of the source observable into time windows of `duration`, and emits each window as a `HotObservable` when its first value arrives. The window is completed when the duration elapses, and terminated together with the source.
+pub struct WindowTime<O, S> {
+    source: O,
+    duration: Duration,
+    scheduler: Arc<S>,
+}
+
+impl<O, S> WindowTime<O, S> {
+    pub fn new(source: O, duration: Duration, scheduler: S) -> WindowTime<O, S> {
+        WindowTime {
+            source,
+            duration,
+            scheduler: Arc::new(scheduler),
+        }
+    }
+}
+
+impl<O, S> Clone for WindowTime<O, S>
+where
+    O: Clone,
+{
+    fn clone(&self) -> Self {
+        WindowTime {
+            source: self.source.clone(),
+            duration: self.duration,
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+struct WindowTimeState<T, E> {
+    stopped: bool,
+    window: Option<HotEmitter<T, E>>,
+    timer_subscription: Option<Subscription>,
+}
+
+impl<T, E, O, S> Observable<HotObservable<T, E>, E> for WindowTime<O, S>
+where
+    O: Observable<T, E>,
+    S: Scheduler,
+    T: Clone + Sync + Send + 'static,
+    E: Clone + Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<HotObservable<T, E>, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let state = Arc::new(Mutex::new(WindowTimeState {
+            stopped: false,
+            window: None,
+            timer_subscription: None,
+        }));
+
+        let state_cloned = state.clone();
+        let timer_observer = AnonymousObserver::new(move |event: Event<usize, Infallible>| {
+            if let Event::Next(_) = event {
+                let window = state_cloned.lock().unwrap().window.take();
+                if let Some(window) = window {
+                    window.complete();
+                }
+            }
+        });
+        let timer_subscription =
+            Interval::new(self.duration, self.scheduler.clone()).subscribe(timer_observer);
+        state.lock().unwrap().timer_subscription = Some(timer_subscription);
+
+        let state_cloned = state.clone();
+        let source_observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut state = state_cloned.lock().unwrap();
+                if state.stopped {
+                    return;
+                }
+                let (window, opened) = match &state.window {
+                    Some(window) => (window.clone(), None),
+                    None => {
+                        let (window, observable) = HotObservable::new();
+                        state.window = Some(window.clone());
+                        (window, Some(observable))
+                    }
+                };
+                drop(state);
+                if let Some(observable) = opened {
+                    // Emitted before its first value, so the downstream can subscribe to it in time.
+                    observer.notify_if_unterminated(Event::Next(observable));
+                }
+                window.emit(value);
+            }
+            Event::Terminated(terminated) => {
+                let mut state = state_cloned.lock().unwrap();
+                state.stopped = true;
+                let window = state.window.take();
+                let timer_subscription = state.timer_subscription.take();
+                drop(state);
+                drop(timer_subscription);
+                if let Some(window) = window {
+                    match &terminated {
+                        Terminated::Error(error) => window.error(error.clone()),
+                        _ => window.complete(),
+                    }
+                }
+                observer.notify_if_unterminated(Event::Terminated(terminated));
+            }
+        });
+        let subscription = self.source.subscribe(source_observer);
+        subscription.insert_disposal_action(move || {
+            let mut state = state.lock().unwrap();
+            state.stopped = true;
+            let timer_subscription = state.timer_subscription.take();
+            drop(state);
+            drop(timer_subscription);
+        })
+    }
+}
+
+/// Make the `Observable` windowable by time.
+pub trait WindowTimeObservable<T, E> {
+    /**
+    Splits the values into time windows of `duration`, and emits each window as a `HotObservable` when its first value arrives, so each window can be aggregated as a stream instead of buffering it into a `Vec`. Subscribe to the window when it is emitted to receive all its values.
+    The window is completed when the duration elapses, completed when the source completes or is unsubscribed, and receives the error when the source errors. The timer is cancelled when the subscription is unsubscribed or dropped.
+
+    # Example
+    ```rust
+    use rx_rust::operators::interval::Interval;
+    use rx_rust::operators::window_time::WindowTimeObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+    use std::time::Duration;
+    let scheduler = QueueScheduler::new();
+    let observable = Interval::new(Duration::from_millis(10), scheduler.clone());
+    let observable = observable.window_time(Duration::from_millis(35), scheduler.clone());
+    let subscription = observable.subscribe_on_next(|window| {
+        window.subscribe_on_next(|value| println!("{}", value));
+    });
+    scheduler.run_one();
+    ```
+     */
+    fn window_time<S>(
+        self,
+        duration: Duration,
+        scheduler: S,
+    ) -> impl Observable<HotObservable<T, E>, E>
+    where
+        S: Scheduler,
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static;
+}
+
+impl<O, T, E> WindowTimeObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn window_time<S>(
+        self,
+        duration: Duration,
+        scheduler: S,
+    ) -> impl Observable<HotObservable<T, E>, E>
+    where
+        S: Scheduler,
+        T: Clone + Sync + Send + 'static,
+        E: Clone + Sync + Send + 'static,
+    {
+        WindowTime::new(self, duration, scheduler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        scheduler::queue_scheduler::QueueScheduler,
+        utils::{checking_observer::CheckingObserver, timed_source::timed_source},
+    };
+
+    /// The checker and the subscription of each window.
+    type Windows = Arc<Mutex<Vec<(CheckingObserver<i32, String>, Subscription)>>>;
+
+    /// Subscribe to the windows as they are emitted.
+    fn subscribe_windows(
+        observable: impl Observable<HotObservable<i32, String>, String>,
+    ) -> (
+        CheckingObserver<HotObservable<i32, String>, String>,
+        Windows,
+        Subscription,
+    ) {
+        let checker = CheckingObserver::new();
+        let windows = Arc::new(Mutex::new(Vec::new()));
+        let windows_cloned = windows.clone();
+        let checker_cloned = checker.clone();
+        let subscription = observable.subscribe(AnonymousObserver::new(
+            move |event: Event<HotObservable<i32, String>, String>| {
+                if let Event::Next(window) = &event {
+                    let window_checker = CheckingObserver::new();
+                    let window_subscription = window.clone().subscribe(window_checker.clone());
+                    windows_cloned
+                        .lock()
+                        .unwrap()
+                        .push((window_checker, window_subscription));
+                }
+                checker_cloned.notify_if_unterminated(event);
+            },
+        ));
+        (checker, windows, subscription)
+    }
+
+    #[test]
+    fn test_completed() {
+        let scheduler = QueueScheduler::new();
+        let (checker, windows, subscription) = subscribe_windows(
+            timed_source(
+                scheduler.clone(),
+                vec![
+                    (5, Event::Next(1)),
+                    (8, Event::Next(2)),
+                    (15, Event::Next(3)),
+                    (35, Event::Next(4)),
+                    (38, Event::Terminated(Terminated::Completed)),
+                ],
+            )
+            .window_time(Duration::from_millis(10), scheduler.clone()),
+        );
+        scheduler.run_one();
+        scheduler.run_one();
+        assert!(windows.lock().unwrap()[0].0.is_values_matched(&[1, 2]));
+        assert!(windows.lock().unwrap()[0].0.is_unterminated());
+        scheduler.run_until_idle();
+        let windows = windows.lock().unwrap();
+        assert_eq!(windows.len(), 3);
+        assert!(windows[0].0.is_completed());
+        assert!(windows[1].0.is_values_matched(&[3]));
+        assert!(windows[1].0.is_completed());
+        assert!(windows[2].0.is_values_matched(&[4]));
+        assert!(windows[2].0.is_completed());
+        assert!(checker.is_completed());
+        assert_eq!(scheduler.now(), Duration::from_millis(38));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_error() {
+        let scheduler = QueueScheduler::new();
+        let (checker, windows, subscription) = subscribe_windows(
+            timed_source(
+                scheduler.clone(),
+                vec![
+                    (5, Event::Next(1)),
+                    (8, Event::Terminated(Terminated::Error("error".to_owned()))),
+                ],
+            )
+            .window_time(Duration::from_millis(10), scheduler.clone()),
+        );
+        scheduler.run_until_idle();
+        let windows = windows.lock().unwrap();
+        assert!(windows[0].0.is_values_matched(&[1]));
+        assert!(windows[0].0.is_error("error".to_owned()));
+        assert!(checker.is_error("error".to_owned()));
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let scheduler = QueueScheduler::new();
+        let (checker, windows, subscription) = subscribe_windows(
+            timed_source(scheduler.clone(), vec![(5, Event::Next(1))])
+                .window_time(Duration::from_millis(10), scheduler.clone()),
+        );
+        scheduler.run_one();
+        subscription.unsubscribe();
+        assert!(checker.is_unsubscribed());
+        assert!(windows.lock().unwrap()[0].0.is_completed());
+        assert_eq!(scheduler.pending(), 0);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    observable::Observable,
+    observer::{anonymous_observer::AnonymousObserver, event::Event, Observer},
+    subscription::Subscription,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/// This is an observable that emits each value of the source observable together with a fixed-size array of its `N` predecessors, oldest first. Nothing is emitted until the source has emitted `N + 1` values.
+#[derive(Clone)]
+pub struct WithPreviousN<O, const N: usize> {
+    source: O,
+}
+
+impl<O, const N: usize> WithPreviousN<O, N> {
+    /// Panics if `N` is 0.
+    pub fn new(source: O) -> WithPreviousN<O, N> {
+        assert!(N > 0, "the N of with_previous_n must be positive");
+        WithPreviousN { source }
+    }
+}
+
+impl<T, E, O, const N: usize> Observable<([T; N], T), E> for WithPreviousN<O, N>
+where
+    O: Observable<T, E>,
+    T: Clone + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<([T; N], T), E>) -> Subscription {
+        let history = Arc::new(Mutex::new(VecDeque::with_capacity(N + 1)));
+        let observer = AnonymousObserver::new(move |event: Event<T, E>| match event {
+            Event::Next(value) => {
+                let mut history = history.lock().unwrap();
+                history.push_back(value);
+                if history.len() > N + 1 {
+                    history.pop_front();
+                }
+                if history.len() <= N {
+                    return;
+                }
+                let previous = std::array::from_fn(|index| history[index].clone());
+                let current = history[N].clone();
+                drop(history);
+                observer.notify_if_unterminated(Event::Next((previous, current)));
+            }
+            Event::Terminated(terminated) => {
+                observer.notify_if_unterminated(Event::Terminated(terminated))
+            }
+        });
+        self.source.subscribe(observer)
+    }
+}
+
+/// Make the `Observable` emit the previous values together with the current value.
+pub trait WithPreviousNObservable<T, E> {
+    /**
+    Emits the current value together with its `N` predecessors as `([T; N], T)`, the predecessors oldest first. `with_previous_n::<1>()` emits each value with the one before it. Nothing is emitted until the source has emitted `N + 1` values.
+
+    Panics if `N` is 0.
+
+    # Example
+    ```rust
+    use rx_rust::operators::just::Just;
+    use rx_rust::operators::with_previous_n::WithPreviousNObservable;
+    use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+    let observable = Just::new(333);
+    let observable = observable.with_previous_n::<2>();
+    observable.subscribe_on_next(|([oldest, previous], current)| {
+        println!("{} {} {}", oldest, previous, current);
+    });
+    ```
+     */
+    fn with_previous_n<const N: usize>(self) -> impl Observable<([T; N], T), E>
+    where
+        T: Clone + Send + 'static;
+}
+
+impl<O, T, E> WithPreviousNObservable<T, E> for O
+where
+    O: Observable<T, E>,
+{
+    fn with_previous_n<const N: usize>(self) -> impl Observable<([T; N], T), E>
+    where
+        T: Clone + Send + 'static,
+    {
+        WithPreviousN::<O, N>::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observer::event::Terminated,
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_completed() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            for value in 1..=4 {
+                observer.notify_if_unterminated(Event::Next(value));
+            }
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.with_previous_n::<2>();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[([1, 2], 3), ([2, 3], 4)]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_not_enough_values() {
+        let observable = Just::new(333).with_previous_n::<1>();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.with_previous_n::<1>();
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[([1], 2)]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_multiple_subscribe() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observable = observable.with_previous_n::<1>();
+
+        let checker = CheckingObserver::new();
+        observable.clone().subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[([1], 2)]));
+
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[([1], 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "the N of with_previous_n must be positive")]
+    fn test_zero() {
+        Just::new(333).with_previous_n::<0>();
+    }
+}
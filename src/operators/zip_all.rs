@@ -0,0 +1,226 @@
+use crate::{
+    observable::Observable,
+    observer::{
+        anonymous_observer::AnonymousObserver,
+        event::{Event, Terminated},
+        Observer,
+    },
+    subscription::Subscription,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/**
+This is an observable that zips a runtime-known number of sources. It emits a `Vec` row of the n-th values of all sources, in the order of the sources.
+It completes when any source has completed and all its values have been zipped, and errors as soon as any source errors.
+
+# Example
+```rust
+use rx_rust::operators::just::Just;
+use rx_rust::operators::zip_all::ZipAll;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+let observable = ZipAll::new(vec![Just::new(1), Just::new(2), Just::new(3)]);
+observable.subscribe_on_event(|event| println!("event: {:?}", event));
+```
+ */
+#[derive(Clone)]
+pub struct ZipAll<O> {
+    sources: Vec<O>,
+}
+
+impl<O> ZipAll<O> {
+    pub fn new(sources: impl IntoIterator<Item = O>) -> ZipAll<O> {
+        ZipAll {
+            sources: sources.into_iter().collect(),
+        }
+    }
+}
+
+struct ZipAllState<T> {
+    finished: bool,
+    buffers: Vec<VecDeque<T>>,
+    completed: Vec<bool>,
+}
+
+impl<T> ZipAllState<T> {
+    fn is_exhausted(&self) -> bool {
+        self.buffers
+            .iter()
+            .zip(&self.completed)
+            .any(|(buffer, completed)| *completed && buffer.is_empty())
+    }
+}
+
+impl<T, E, O> Observable<Vec<T>, E> for ZipAll<O>
+where
+    O: Observable<T, E>,
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn subscribe(self, observer: impl Observer<Vec<T>, E>) -> Subscription {
+        let observer = Arc::new(observer);
+        let count = self.sources.len();
+        if count == 0 {
+            observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            return Subscription::new_non_disposal_action(observer);
+        }
+        let state = Arc::new(Mutex::new(ZipAllState {
+            finished: false,
+            buffers: (0..count).map(|_| VecDeque::new()).collect(),
+            completed: vec![false; count],
+        }));
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        for (index, source) in self.sources.into_iter().enumerate() {
+            if state.lock().unwrap().finished {
+                break;
+            }
+            let observer = observer.clone();
+            let state_cloned = state.clone();
+            let subscriptions_cloned = subscriptions.clone();
+            let source_observer = AnonymousObserver::new(move |event: Event<T, E>| {
+                let mut state = state_cloned.lock().unwrap();
+                if state.finished {
+                    return;
+                }
+                let mut row = None;
+                match event {
+                    Event::Next(value) => {
+                        state.buffers[index].push_back(value);
+                        if state.buffers.iter().all(|buffer| !buffer.is_empty()) {
+                            row = Some(
+                                state
+                                    .buffers
+                                    .iter_mut()
+                                    .map(|buffer| buffer.pop_front().unwrap())
+                                    .collect(),
+                            );
+                        }
+                    }
+                    Event::Terminated(Terminated::Completed) => state.completed[index] = true,
+                    Event::Terminated(Terminated::Error(error)) => {
+                        state.finished = true;
+                        drop(state);
+                        observer
+                            .notify_if_unterminated(Event::Terminated(Terminated::Error(error)));
+                        let subscriptions: Vec<Subscription> =
+                            subscriptions_cloned.lock().unwrap().drain(..).collect();
+                        drop(subscriptions);
+                        return;
+                    }
+                    Event::Terminated(Terminated::Unsubscribed) => return,
+                }
+                let exhausted = state.is_exhausted();
+                state.finished = exhausted;
+                drop(state);
+                if let Some(row) = row {
+                    observer.notify_if_unterminated(Event::Next(row));
+                }
+                if exhausted {
+                    observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+                    let subscriptions: Vec<Subscription> =
+                        subscriptions_cloned.lock().unwrap().drain(..).collect();
+                    drop(subscriptions);
+                }
+            });
+            let subscription = source.subscribe(source_observer);
+            if state.lock().unwrap().finished {
+                drop(subscription);
+            } else {
+                subscriptions.lock().unwrap().push(subscription);
+            }
+        }
+        Subscription::new(observer, move || {
+            let subscriptions: Vec<Subscription> =
+                subscriptions.lock().unwrap().drain(..).collect();
+            drop(subscriptions);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        operators::{create::Create, just::Just},
+        utils::checking_observer::CheckingObserver,
+    };
+
+    fn source(
+        values: Vec<i32>,
+        terminated: Option<Terminated<String>>,
+    ) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            for value in values.iter() {
+                observer.notify_if_unterminated(Event::Next(*value));
+            }
+            match &terminated {
+                Some(Terminated::Completed) => {
+                    observer.notify_if_unterminated(Event::Terminated(Terminated::Completed))
+                }
+                Some(Terminated::Error(error)) => observer
+                    .notify_if_unterminated(Event::Terminated(Terminated::Error(error.clone()))),
+                _ => {}
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = ZipAll::new(vec![
+            source(vec![1, 2, 3], Some(Terminated::Completed)),
+            source(vec![4, 5], None),
+            source(vec![6, 7, 8], Some(Terminated::Completed)),
+        ]);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 4, 6], vec![2, 5, 7]]));
+        assert!(checker.is_unterminated());
+        _ = subscription; // keep the subscription alive
+    }
+
+    #[test]
+    fn test_completed_when_exhausted() {
+        let observable = ZipAll::new(vec![
+            source(vec![1, 2], Some(Terminated::Completed)),
+            source(vec![3, 4, 5], None),
+        ]);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 3], vec![2, 4]]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_error() {
+        let observable = ZipAll::new(vec![
+            source(vec![1, 2], None),
+            source(vec![3], Some(Terminated::Error("error".to_owned()))),
+        ]);
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[vec![1, 3]]));
+        assert!(checker.is_error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_empty_sources() {
+        let observable = ZipAll::new(Vec::<Just<i32>>::new());
+        let checker = CheckingObserver::new();
+        observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_completed());
+    }
+
+    #[test]
+    fn test_unsubscribed() {
+        let observable = ZipAll::new(vec![source(vec![1], None), source(vec![2], None)]);
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        subscription.unsubscribe();
+        assert!(checker.is_values_matched(&[vec![1, 2]]));
+        assert!(checker.is_unsubscribed());
+    }
+}
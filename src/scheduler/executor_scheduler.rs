@@ -0,0 +1,308 @@
+use super::Scheduler;
+use crate::utils::disposal::Disposal;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// A `Spawn` is a type that can run tasks, e.g. a thread pool or a handle of an async runtime.
+/// Spawn must be Sync and Send because it will be used in multiple threads.
+/// Spawn must be 'static because it will be stored in the scheduler.
+pub trait Spawn: Sync + Send + 'static {
+    /// Run the task as soon as possible.
+    fn spawn(&self, task: Box<dyn FnOnce() + Send + 'static>);
+}
+
+impl<F> Spawn for F
+where
+    F: Fn(Box<dyn FnOnce() + Send + 'static>) + Sync + Send + 'static,
+{
+    fn spawn(&self, task: Box<dyn FnOnce() + Send + 'static>) {
+        self(task);
+    }
+}
+
+#[cfg(feature = "tokio-scheduler")]
+impl Spawn for tokio::runtime::Handle {
+    fn spawn(&self, task: Box<dyn FnOnce() + Send + 'static>) {
+        tokio::runtime::Handle::spawn(self, async move { task() });
+    }
+}
+
+type Task = Box<dyn FnOnce() + Send + 'static>;
+
+struct TimerQueue {
+    next_id: u64,
+    /// The delayed tasks by due time, the earliest first. The id keeps the tasks due at the same time apart.
+    tasks: BTreeMap<(Instant, u64), Task>,
+    started: bool,
+    shutdown: bool,
+}
+
+struct TimerShared {
+    queue: Mutex<TimerQueue>,
+    condvar: Condvar,
+}
+
+impl TimerShared {
+    /// Wait for the delayed tasks and hand each to the spawner when it's due. Returns once the scheduler is dropped and no task is left.
+    fn run(&self, spawner: &impl Spawn) {
+        let mut queue = self.queue.lock().unwrap();
+        loop {
+            if queue.shutdown && queue.tasks.is_empty() {
+                return;
+            }
+            let Some(&(deadline, _)) = queue.tasks.keys().next() else {
+                queue = self.condvar.wait(queue).unwrap();
+                continue;
+            };
+            let now = Instant::now();
+            if deadline > now {
+                queue = self.condvar.wait_timeout(queue, deadline - now).unwrap().0;
+                continue;
+            }
+            let (_, task) = queue.tasks.pop_first().unwrap();
+            drop(queue);
+            spawner.spawn(task);
+            queue = self.queue.lock().unwrap();
+        }
+    }
+}
+
+/// The owner of the timer thread, shared by the scheduler and its clones. The thread stops when they are all dropped.
+struct Timer {
+    shared: Arc<TimerShared>,
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.shared.queue.lock().unwrap().shutdown = true;
+        self.shared.condvar.notify_all();
+    }
+}
+
+/**
+A scheduler that runs the tasks on any user-provided `Spawn`.
+The delays are waited on a single timer thread shared by the scheduler and its clones, so the spawner's workers are not blocked. The task won't run if the `Disposal` is disposed before it starts, and a disposed delayed task is released right away.
+
+# Example
+```rust
+use rx_rust::scheduler::executor_scheduler::ExecutorScheduler;
+use rx_rust::scheduler::Scheduler;
+let scheduler = ExecutorScheduler::new(|task: Box<dyn FnOnce() + Send>| {
+    std::thread::spawn(task);
+});
+let disposal = scheduler.schedule(|| println!("Hello"), None);
+```
+*/
+pub struct ExecutorScheduler<S> {
+    spawner: Arc<S>,
+    timer: Arc<Timer>,
+}
+
+impl<S> ExecutorScheduler<S> {
+    pub fn new(spawner: S) -> ExecutorScheduler<S> {
+        ExecutorScheduler {
+            spawner: Arc::new(spawner),
+            timer: Arc::new(Timer {
+                shared: Arc::new(TimerShared {
+                    queue: Mutex::new(TimerQueue {
+                        next_id: 0,
+                        tasks: BTreeMap::new(),
+                        started: false,
+                        shutdown: false,
+                    }),
+                    condvar: Condvar::new(),
+                }),
+            }),
+        }
+    }
+}
+
+impl<S> Clone for ExecutorScheduler<S> {
+    fn clone(&self) -> Self {
+        ExecutorScheduler {
+            spawner: self.spawner.clone(),
+            timer: self.timer.clone(),
+        }
+    }
+}
+
+impl<S> ExecutorScheduler<S>
+where
+    S: Spawn,
+{
+    /// Queue the task on the timer thread, starting the thread on first use. Returns the key of the task.
+    fn schedule_delayed(&self, task: Task, delay: Duration) -> (Instant, u64) {
+        let shared = &self.timer.shared;
+        let mut queue = shared.queue.lock().unwrap();
+        let key = (Instant::now() + delay, queue.next_id);
+        queue.next_id += 1;
+        queue.tasks.insert(key, task);
+        let start = !queue.started;
+        queue.started = true;
+        drop(queue);
+        if start {
+            let shared = shared.clone();
+            let spawner = self.spawner.clone();
+            std::thread::Builder::new()
+                .name("rx-rust-executor-timer".to_owned())
+                .spawn(move || shared.run(&*spawner))
+                .expect("failed to start the timer thread of ExecutorScheduler");
+        } else {
+            shared.condvar.notify_all();
+        }
+        key
+    }
+}
+
+impl<S> Scheduler for ExecutorScheduler<S>
+where
+    S: Spawn,
+{
+    fn schedule(
+        &self,
+        task: impl FnOnce() + Send + 'static,
+        delay: Option<Duration>,
+    ) -> Disposal<impl FnOnce() + Send + 'static> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancelled_cloned = cancelled.clone();
+        let task: Task = Box::new(move || {
+            if !cancelled_cloned.load(Ordering::SeqCst) {
+                task();
+            }
+        });
+        let delayed = match delay {
+            Some(delay) => Some((
+                self.timer.shared.clone(),
+                self.schedule_delayed(task, delay),
+            )),
+            None => {
+                self.spawner.spawn(task);
+                None
+            }
+        };
+        Disposal::new(move || {
+            cancelled.store(true, Ordering::SeqCst);
+            if let Some((shared, key)) = delayed {
+                let task = shared.queue.lock().unwrap().tasks.remove(&key);
+                drop(task);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    fn thread_spawner(task: Box<dyn FnOnce() + Send + 'static>) {
+        std::thread::spawn(task);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(move || sender.send(333).unwrap(), None);
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(333));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_schedule_with_delay() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(20)),
+        );
+        assert!(receiver.recv_timeout(Duration::from_millis(5)).is_err());
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(333));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_dispose() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(10)),
+        );
+        disposal.dispose();
+        assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[test]
+    fn test_deadline_order() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let (sender, receiver) = channel();
+        let disposals: Vec<_> = [30, 10, 20]
+            .into_iter()
+            .map(|delay| {
+                let sender = sender.clone();
+                scheduler.schedule(
+                    move || sender.send(delay).unwrap(),
+                    Some(Duration::from_millis(delay)),
+                )
+            })
+            .collect();
+        let received: Vec<_> = (0..3)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(1)).unwrap())
+            .collect();
+        assert_eq!(received, vec![10, 20, 30]);
+        _ = disposals;
+    }
+
+    #[test]
+    fn test_dispose_releases_task() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let disposals: Vec<_> = (0..1000)
+            .map(|_| scheduler.schedule(|| {}, Some(Duration::from_secs(60))))
+            .collect();
+        assert_eq!(
+            scheduler.timer.shared.queue.lock().unwrap().tasks.len(),
+            1000
+        );
+        for disposal in disposals {
+            disposal.dispose();
+        }
+        assert!(scheduler
+            .timer
+            .shared
+            .queue
+            .lock()
+            .unwrap()
+            .tasks
+            .is_empty());
+    }
+
+    #[test]
+    fn test_run_after_scheduler_dropped() {
+        let scheduler = ExecutorScheduler::new(thread_spawner);
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(10)),
+        );
+        drop(scheduler);
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(333));
+        _ = disposal;
+    }
+
+    #[cfg(feature = "tokio-scheduler")]
+    #[tokio::test]
+    async fn test_tokio_handle() {
+        let scheduler = ExecutorScheduler::new(tokio::runtime::Handle::current());
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let disposal = scheduler.schedule(move || sender.send(333).unwrap(), None);
+        assert_eq!(receiver.recv().await, Some(333));
+        _ = disposal;
+    }
+}
@@ -1,6 +1,8 @@
 use crate::utils::disposal::Disposal;
 use std::time::Duration;
 
+pub mod executor_scheduler;
+pub mod queue_scheduler;
 #[cfg(feature = "tokio-scheduler")]
 pub mod tokio_scheduler;
 
@@ -18,3 +20,16 @@ pub trait Scheduler: Sync + Send + 'static {
         delay: Option<Duration>,
     ) -> Disposal<impl FnOnce() + Send + 'static>;
 }
+
+impl<S> Scheduler for std::sync::Arc<S>
+where
+    S: Scheduler,
+{
+    fn schedule(
+        &self,
+        task: impl FnOnce() + Send + 'static,
+        delay: Option<Duration>,
+    ) -> Disposal<impl FnOnce() + Send + 'static> {
+        self.as_ref().schedule(task, delay)
+    }
+}
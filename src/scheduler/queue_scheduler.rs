@@ -0,0 +1,196 @@
+use super::Scheduler;
+use crate::utils::disposal::Disposal;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+type Task = Box<dyn FnOnce() + Send + 'static>;
+
+struct QueueState {
+    now: Duration,
+    next_id: u64,
+    tasks: BTreeMap<(Duration, u64), Task>,
+}
+
+/**
+A deterministic single-threaded scheduler. The tasks are recorded into a queue and only run when `run_one` or `run_until_idle` is called, without any runtime.
+The delays are measured on a virtual clock, which jumps to the due time of each task when it runs. Tasks with the same due time run in the order they were scheduled.
+
+# Example
+```rust
+use rx_rust::operators::just::Just;
+use rx_rust::operators::delay::DelayableObservable;
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+use std::time::Duration;
+let scheduler = QueueScheduler::new();
+let observable = Just::new(333).delay(Duration::from_secs(1), scheduler.clone());
+let subscription = observable.subscribe_on_next(|value| println!("{}", value));
+scheduler.run_until_idle();
+assert_eq!(scheduler.now(), Duration::from_secs(1));
+```
+*/
+#[derive(Clone)]
+pub struct QueueScheduler {
+    state: Arc<Mutex<QueueState>>,
+}
+
+impl QueueScheduler {
+    pub fn new() -> QueueScheduler {
+        QueueScheduler {
+            state: Arc::new(Mutex::new(QueueState {
+                now: Duration::ZERO,
+                next_id: 0,
+                tasks: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// The virtual time elapsed since the scheduler was created.
+    pub fn now(&self) -> Duration {
+        self.state.lock().unwrap().now
+    }
+
+    /// The number of tasks waiting in the queue.
+    pub fn pending(&self) -> usize {
+        self.state.lock().unwrap().tasks.len()
+    }
+
+    /// Run the earliest task in the queue. Returns false if the queue is empty.
+    pub fn run_one(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let ((due, _), task) = match state.tasks.pop_first() {
+            Some(entry) => entry,
+            None => return false,
+        };
+        state.now = state.now.max(due);
+        drop(state);
+        task();
+        true
+    }
+
+    /// Run the tasks until the queue is empty, including the tasks scheduled while running. Returns the number of tasks run.
+    /// It never returns if the tasks keep scheduling new tasks, e.g. an interval.
+    pub fn run_until_idle(&self) -> usize {
+        let mut count = 0;
+        while self.run_one() {
+            count += 1;
+        }
+        count
+    }
+}
+
+impl Default for QueueScheduler {
+    fn default() -> Self {
+        QueueScheduler::new()
+    }
+}
+
+impl Scheduler for QueueScheduler {
+    fn schedule(
+        &self,
+        task: impl FnOnce() + Send + 'static,
+        delay: Option<Duration>,
+    ) -> Disposal<impl FnOnce() + Send + 'static> {
+        let mut state = self.state.lock().unwrap();
+        let key = (state.now + delay.unwrap_or_default(), state.next_id);
+        state.next_id += 1;
+        state.tasks.insert(key, Box::new(task));
+        drop(state);
+        let state = self.state.clone();
+        Disposal::new(move || {
+            let task = state.lock().unwrap().tasks.remove(&key);
+            drop(task);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        observable::Observable, operators::delay::DelayableObservable, operators::just::Just,
+        utils::checking_observer::CheckingObserver,
+    };
+
+    #[test]
+    fn test_run_in_due_order() {
+        let scheduler = QueueScheduler::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let order_cloned = order.clone();
+        let disposal1 = scheduler.schedule(
+            move || order_cloned.lock().unwrap().push(1),
+            Some(Duration::from_millis(20)),
+        );
+        let order_cloned = order.clone();
+        let disposal2 = scheduler.schedule(move || order_cloned.lock().unwrap().push(2), None);
+        let order_cloned = order.clone();
+        let disposal3 = scheduler.schedule(
+            move || order_cloned.lock().unwrap().push(3),
+            Some(Duration::from_millis(10)),
+        );
+        assert_eq!(scheduler.pending(), 3);
+        assert!(order.lock().unwrap().is_empty());
+        assert!(scheduler.run_one());
+        assert_eq!(*order.lock().unwrap(), vec![2]);
+        assert_eq!(scheduler.now(), Duration::ZERO);
+        assert_eq!(scheduler.run_until_idle(), 2);
+        assert_eq!(*order.lock().unwrap(), vec![2, 3, 1]);
+        assert_eq!(scheduler.now(), Duration::from_millis(20));
+        assert!(!scheduler.run_one());
+        _ = (disposal1, disposal2, disposal3);
+    }
+
+    #[test]
+    fn test_dispose() {
+        let scheduler = QueueScheduler::new();
+        let ran = Arc::new(Mutex::new(false));
+        let ran_cloned = ran.clone();
+        let disposal = scheduler.schedule(move || *ran_cloned.lock().unwrap() = true, None);
+        disposal.dispose();
+        assert_eq!(scheduler.pending(), 0);
+        assert_eq!(scheduler.run_until_idle(), 0);
+        assert!(!*ran.lock().unwrap());
+    }
+
+    #[test]
+    fn test_nested_schedule() {
+        let scheduler = QueueScheduler::new();
+        let scheduler_cloned = scheduler.clone();
+        let ran = Arc::new(Mutex::new(false));
+        let ran_cloned = ran.clone();
+        let disposal = scheduler.schedule(
+            move || {
+                let disposal = scheduler_cloned.schedule(
+                    move || *ran_cloned.lock().unwrap() = true,
+                    Some(Duration::from_millis(5)),
+                );
+                std::mem::forget(disposal);
+            },
+            Some(Duration::from_millis(5)),
+        );
+        assert_eq!(scheduler.run_until_idle(), 2);
+        assert!(*ran.lock().unwrap());
+        assert_eq!(scheduler.now(), Duration::from_millis(10));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_with_delay() {
+        let scheduler = QueueScheduler::new();
+        let observable = Just::new(333).delay(Duration::from_millis(10), scheduler.clone());
+        let checker = CheckingObserver::new();
+        let subscription = observable.subscribe(checker.clone());
+        assert!(checker.is_values_matched(&[]));
+        assert!(checker.is_unterminated());
+        assert!(scheduler.run_one());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_unterminated());
+        assert!(scheduler.run_one());
+        assert!(checker.is_values_matched(&[333]));
+        assert!(checker.is_completed());
+        _ = subscription; // keep the subscription alive
+    }
+}
@@ -1,8 +1,68 @@
 use super::Scheduler;
 use crate::utils::disposal::Disposal;
-use std::time::Duration;
+use std::{sync::OnceLock, time::Duration};
+use tokio::runtime::{Builder, Handle, Runtime};
 
-pub struct TokioScheduler;
+/**
+A scheduler that runs the tasks on a Tokio runtime.
+`TokioScheduler::new()` uses the runtime of the thread calling `schedule`. On a thread outside of any runtime, it falls back to a multi-threaded runtime shared by the whole process, which is created on first use.
+`TokioScheduler::with_handle(handle)` always uses the given runtime.
+`TokioScheduler` is not a unit struct, so create it with `TokioScheduler::new()` or `TokioScheduler::default()`.
+`blocking()` makes the scheduler run the tasks with `spawn_blocking`, so CPU or IO heavy tasks don't starve the async runtime.
+
+# Example
+```rust
+use rx_rust::scheduler::tokio_scheduler::TokioScheduler;
+use rx_rust::scheduler::Scheduler;
+let runtime = tokio::runtime::Runtime::new().unwrap();
+let scheduler = TokioScheduler::with_handle(runtime.handle().clone());
+let disposal = scheduler.schedule(|| println!("Hello"), None);
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct TokioScheduler {
+    handle: Option<Handle>,
+    blocking: bool,
+}
+
+impl TokioScheduler {
+    pub fn new() -> TokioScheduler {
+        TokioScheduler {
+            handle: None,
+            blocking: false,
+        }
+    }
+
+    pub fn with_handle(handle: Handle) -> TokioScheduler {
+        TokioScheduler {
+            handle: Some(handle),
+            blocking: false,
+        }
+    }
+
+    /// Run the tasks on the blocking thread pool of the runtime. A task can't be cancelled once it has started.
+    pub fn blocking(self) -> TokioScheduler {
+        TokioScheduler {
+            blocking: true,
+            ..self
+        }
+    }
+}
+
+/// The runtime of `TokioScheduler::new()` on threads outside of any runtime.
+fn fallback_handle() -> Handle {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_multi_thread()
+                .enable_all()
+                .thread_name("rx-rust-tokio-scheduler")
+                .build()
+                .expect("failed to create the fallback Tokio runtime of TokioScheduler")
+        })
+        .handle()
+        .clone()
+}
 
 impl Scheduler for TokioScheduler {
     fn schedule(
@@ -10,12 +70,104 @@ impl Scheduler for TokioScheduler {
         task: impl FnOnce() + Send + 'static,
         delay: Option<Duration>,
     ) -> Disposal<impl FnOnce() + Send + 'static> {
-        let handle = tokio::spawn(async move {
+        let handle = match &self.handle {
+            Some(handle) => handle.clone(),
+            None => Handle::try_current().unwrap_or_else(|_| fallback_handle()),
+        };
+        let blocking = self.blocking;
+        let handle = handle.spawn(async move {
             if let Some(delay) = delay {
                 tokio::time::sleep(delay).await;
             }
-            task();
+            if blocking {
+                tokio::task::spawn_blocking(task);
+            } else {
+                task();
+            }
         });
         Disposal::new(move || handle.abort())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+
+    #[tokio::test]
+    async fn test_current_runtime() {
+        let scheduler = TokioScheduler::new();
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let disposal = scheduler.schedule(move || sender.send(333).unwrap(), None);
+        assert_eq!(receiver.recv().await, Some(333));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_with_handle_outside_runtime() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let scheduler = TokioScheduler::with_handle(runtime.handle().clone());
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(5)),
+        );
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(333));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_without_runtime() {
+        let scheduler = TokioScheduler::new();
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(5)),
+        );
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(333));
+        _ = disposal;
+    }
+
+    #[test]
+    fn test_dispose_without_runtime() {
+        let scheduler = TokioScheduler::new();
+        let (sender, receiver) = channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(5)),
+        );
+        disposal.dispose();
+        assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_blocking() {
+        let scheduler = TokioScheduler::new().blocking();
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let start = std::time::Instant::now();
+        let disposal = scheduler.schedule(
+            move || {
+                std::thread::sleep(Duration::from_millis(50));
+                sender.send(333).unwrap();
+            },
+            None,
+        );
+        // The runtime is not blocked by the task.
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(start.elapsed() < Duration::from_millis(40));
+        assert_eq!(receiver.recv().await, Some(333));
+        _ = disposal;
+    }
+
+    #[tokio::test]
+    async fn test_dispose() {
+        let scheduler = TokioScheduler::new();
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let disposal = scheduler.schedule(
+            move || sender.send(333).unwrap(),
+            Some(Duration::from_millis(5)),
+        );
+        disposal.dispose();
+        assert_eq!(receiver.recv().await, None);
+    }
+}
@@ -0,0 +1,120 @@
+pub mod test_observer;
+
+pub use crate::assert_stream;
+
+/**
+Subscribes to the observable with a `TestObserver`, and asserts the values and the terminated event it receives.
+The terminated event is one of `completed`, `error(expr)`, `unsubscribed` and `unterminated`. If a `QueueScheduler` is given as the last argument, it's run until idle before the assertions.
+The subscription is kept alive until the assertions are done.
+
+# Example
+```rust
+use rx_rust::operators::delay::DelayableObservable;
+use rx_rust::operators::just::Just;
+use rx_rust::scheduler::queue_scheduler::QueueScheduler;
+use rx_rust::testing::assert_stream;
+use std::time::Duration;
+assert_stream!(Just::new(333), [333], completed);
+
+let scheduler = QueueScheduler::new();
+let observable = Just::new(333).delay(Duration::from_secs(1), scheduler.clone());
+assert_stream!(observable, [333], completed, scheduler);
+```
+*/
+#[macro_export]
+macro_rules! assert_stream {
+    (@terminal completed) => {
+        Some($crate::observer::event::Terminated::Completed)
+    };
+    (@terminal unsubscribed) => {
+        Some($crate::observer::event::Terminated::Unsubscribed)
+    };
+    (@terminal unterminated) => {
+        None
+    };
+    (@terminal error($error:expr)) => {
+        Some($crate::observer::event::Terminated::Error($error))
+    };
+    ($observable:expr, [$($value:expr),* $(,)?], $terminal:ident $(($error:expr))? $(, $scheduler:expr)? $(,)?) => {{
+        let observer = $crate::testing::test_observer::TestObserver::new();
+        let subscription = $crate::observable::Observable::subscribe($observable, observer.clone());
+        $($scheduler.run_until_idle();)?
+        let expected_values = vec![$($value),*];
+        assert_eq!(
+            observer.values(),
+            expected_values,
+            "the values of `{}` don't match",
+            stringify!($observable)
+        );
+        assert_eq!(
+            observer.terminal(),
+            $crate::assert_stream!(@terminal $terminal $(($error))?),
+            "the terminated event of `{}` doesn't match",
+            stringify!($observable)
+        );
+        drop(subscription);
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        observable::Observable,
+        observer::{
+            event::{Event, Terminated},
+            Observer,
+        },
+        operators::{
+            create::Create, delay::DelayableObservable, just::Just, map::MappableObservable,
+        },
+        scheduler::queue_scheduler::QueueScheduler,
+        subscription::Subscription,
+    };
+    use std::time::Duration;
+
+    fn source(completed: bool) -> impl Observable<i32, String> {
+        Create::new(move |observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            if completed {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Completed));
+            } else {
+                observer.notify_if_unterminated(Event::Terminated(Terminated::Error(
+                    "error".to_owned(),
+                )));
+            }
+            Subscription::new_non_disposal_action(observer)
+        })
+    }
+
+    #[test]
+    fn test_completed() {
+        assert_stream!(source(true).map(|value| value * 10), [10, 20], completed);
+    }
+
+    #[test]
+    fn test_error() {
+        assert_stream!(source(false), [1, 2], error("error".to_owned()));
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            Subscription::new_non_disposal_action(observer)
+        });
+        assert_stream!(observable, [], unterminated);
+    }
+
+    #[test]
+    fn test_scheduler() {
+        let scheduler = QueueScheduler::new();
+        let observable = Just::new(333).delay(Duration::from_secs(1), scheduler.clone());
+        assert_stream!(observable, [333], completed, scheduler);
+    }
+
+    #[test]
+    #[should_panic(expected = "the values of `source(true)` don't match")]
+    fn test_mismatch() {
+        assert_stream!(source(true), [1], completed);
+    }
+}
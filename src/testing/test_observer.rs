@@ -0,0 +1,129 @@
+use crate::observer::{
+    event::{Event, Terminated},
+    Observer,
+};
+use std::sync::{Arc, RwLock};
+
+/**
+An observer that records the events it receives, for testing observables. Clones share the records.
+
+# Example
+```rust
+use rx_rust::observable::Observable;
+use rx_rust::observer::event::Terminated;
+use rx_rust::operators::just::Just;
+use rx_rust::testing::test_observer::TestObserver;
+let observer = TestObserver::new();
+Just::new(123).subscribe(observer.clone());
+assert_eq!(observer.values(), vec![123]);
+assert_eq!(observer.terminal(), Some(Terminated::Completed));
+```
+*/
+#[derive(Debug)]
+pub struct TestObserver<T, E> {
+    events: Arc<RwLock<Vec<Event<T, E>>>>,
+    terminated: Arc<RwLock<bool>>,
+}
+
+impl<T, E> TestObserver<T, E> {
+    pub fn new() -> TestObserver<T, E> {
+        TestObserver {
+            events: Arc::new(RwLock::new(Vec::new())),
+            terminated: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    /// The values received so far.
+    pub fn values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.events
+            .read()
+            .unwrap()
+            .iter()
+            .filter_map(|event| match event {
+                Event::Next(value) => Some(value.clone()),
+                Event::Terminated(_) => None,
+            })
+            .collect()
+    }
+
+    /// The terminated event, or `None` if the observer is unterminated.
+    pub fn terminal(&self) -> Option<Terminated<E>>
+    where
+        E: Clone,
+    {
+        match self.events.read().unwrap().last() {
+            Some(Event::Terminated(terminated)) => Some(terminated.clone()),
+            _ => None,
+        }
+    }
+
+    /// All the events received so far, in order.
+    pub fn events(&self) -> Vec<Event<T, E>>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        self.events.read().unwrap().clone()
+    }
+}
+
+impl<T, E> Default for TestObserver<T, E> {
+    fn default() -> Self {
+        TestObserver::new()
+    }
+}
+
+impl<T, E> Clone for TestObserver<T, E> {
+    fn clone(&self) -> Self {
+        TestObserver {
+            events: self.events.clone(),
+            terminated: self.terminated.clone(),
+        }
+    }
+}
+
+impl<T, E> Observer<T, E> for TestObserver<T, E>
+where
+    T: Sync + Send + 'static,
+    E: Sync + Send + 'static,
+{
+    fn on(&self, event: Event<T, E>) {
+        self.events.write().unwrap().push(event);
+    }
+
+    fn terminated(&self) -> bool {
+        *self.terminated.read().unwrap()
+    }
+
+    fn set_terminated(&self, terminated: bool) {
+        *self.terminated.write().unwrap() = terminated;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{observable::Observable, operators::create::Create, subscription::Subscription};
+
+    #[test]
+    fn test_records() {
+        let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+            observer.notify_if_unterminated(Event::Next(1));
+            observer.notify_if_unterminated(Event::Next(2));
+            observer
+                .notify_if_unterminated(Event::Terminated(Terminated::Error("error".to_owned())));
+            Subscription::new_non_disposal_action(observer)
+        });
+        let observer = TestObserver::new();
+        observable.subscribe(observer.clone());
+        assert_eq!(observer.values(), vec![1, 2]);
+        assert_eq!(
+            observer.terminal(),
+            Some(Terminated::Error("error".to_owned()))
+        );
+        assert_eq!(observer.events().len(), 3);
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// A live subscription of a `named` stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    /// Unique for the lifetime of the process.
+    pub id: u64,
+    pub name: String,
+    /// The nearest named stage downstream of this one in the same subscription, if any.
+    pub downstream: Option<u64>,
+    /// The number of values that have passed through the stage.
+    pub values: usize,
+}
+
+struct Node {
+    name: String,
+    downstream: Option<u64>,
+    values: Arc<AtomicUsize>,
+}
+
+struct Topology {
+    next_id: u64,
+    nodes: BTreeMap<u64, Node>,
+}
+
+static TOPOLOGY: Mutex<Topology> = Mutex::new(Topology {
+    next_id: 0,
+    nodes: BTreeMap::new(),
+});
+
+thread_local! {
+    // The named stages being subscribed on this thread, the innermost last.
+    static SUBSCRIBING: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Register a stage, with the stage being subscribed on this thread as its downstream. Returns the id and the value counter.
+pub(crate) fn register(name: &str) -> (u64, Arc<AtomicUsize>) {
+    let downstream = SUBSCRIBING.with(|stack| stack.borrow().last().copied());
+    let values = Arc::new(AtomicUsize::new(0));
+    let mut topology = TOPOLOGY.lock().unwrap();
+    let id = topology.next_id;
+    topology.next_id += 1;
+    topology.nodes.insert(
+        id,
+        Node {
+            name: name.to_owned(),
+            downstream,
+            values: values.clone(),
+        },
+    );
+    (id, values)
+}
+
+pub(crate) fn unregister(id: u64) {
+    TOPOLOGY.lock().unwrap().nodes.remove(&id);
+}
+
+/// Run `subscribe` with the stage `id` as the downstream of the stages registered inside it.
+pub(crate) fn subscribing<R>(id: u64, subscribe: impl FnOnce() -> R) -> R {
+    SUBSCRIBING.with(|stack| stack.borrow_mut().push(id));
+    let result = subscribe();
+    SUBSCRIBING.with(|stack| stack.borrow_mut().pop());
+    result
+}
+
+/// The live stages, ordered by id.
+pub fn stages() -> Vec<Stage> {
+    let topology = TOPOLOGY.lock().unwrap();
+    topology
+        .nodes
+        .iter()
+        .map(|(id, node)| Stage {
+            id: *id,
+            name: node.name.clone(),
+            downstream: node.downstream,
+            values: node.values.load(Ordering::SeqCst),
+        })
+        .collect()
+}
+
+/**
+Dump the live stages as an indented text tree. Each subscription starts from its most downstream stage, and the upstream stages are indented below it.
+
+# Example
+```rust
+use rx_rust::observable::observable_subscribe_ext::ObservableSubscribeExt;
+use rx_rust::operators::create::Create;
+use rx_rust::operators::named::NamedObservable;
+use rx_rust::observer::Observer;
+use rx_rust::subscription::Subscription;
+use rx_rust::topology;
+let observable = Create::new(|observer: Box<dyn Observer<i32, String>>| {
+    Subscription::new_non_disposal_action(observer)
+});
+let subscription = observable
+    .named("orders")
+    .named("sink")
+    .subscribe_on_next(|value| println!("{}", value));
+println!("{}", topology::dump());
+```
+*/
+pub fn dump() -> String {
+    let stages = stages();
+    let mut text = String::new();
+    for stage in stages.iter().filter(|stage| is_root(stage, &stages)) {
+        write_tree(&mut text, stage, &stages, 0);
+    }
+    text
+}
+
+/// Dump the live stages in the Graphviz DOT format. The edges point downstream.
+pub fn dump_graphviz() -> String {
+    let stages = stages();
+    let mut text = String::from("digraph topology {\n");
+    for stage in stages.iter() {
+        _ = writeln!(
+            text,
+            "    s{} [label=\"{} ({} values)\"];",
+            stage.id,
+            stage.name.replace('"', "\\\""),
+            stage.values
+        );
+    }
+    for stage in stages.iter() {
+        if let Some(downstream) = stage.downstream {
+            if stages.iter().any(|other| other.id == downstream) {
+                _ = writeln!(text, "    s{} -> s{};", stage.id, downstream);
+            }
+        }
+    }
+    text.push_str("}\n");
+    text
+}
+
+fn is_root(stage: &Stage, stages: &[Stage]) -> bool {
+    match stage.downstream {
+        Some(downstream) => !stages.iter().any(|other| other.id == downstream),
+        None => true,
+    }
+}
+
+fn write_tree(text: &mut String, stage: &Stage, stages: &[Stage], depth: usize) {
+    _ = writeln!(
+        text,
+        "{}{} #{} ({} values)",
+        "  ".repeat(depth),
+        stage.name,
+        stage.id,
+        stage.values
+    );
+    for upstream in stages
+        .iter()
+        .filter(|other| other.downstream == Some(stage.id))
+    {
+        write_tree(text, upstream, stages, depth + 1);
+    }
+}
@@ -1,5 +1,4 @@
 /// A struct that calls a function when it is dropped.
-
 pub struct Disposal<F>
 where
     F: FnOnce(),
@@ -1,3 +1,5 @@
 #[cfg(test)]
 pub(crate) mod checking_observer;
 pub mod disposal;
+#[cfg(test)]
+pub(crate) mod timed_source;
@@ -0,0 +1,35 @@
+use crate::{
+    observable::Observable,
+    observer::{event::Event, Observer},
+    operators::create::Create,
+    scheduler::{queue_scheduler::QueueScheduler, Scheduler},
+    subscription::Subscription,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// A helper source for testing time-based observables. Each event is emitted on the scheduler after its delay in milliseconds from the subscription. Only the first subscription receives the events.
+pub(crate) fn timed_source<T>(
+    scheduler: QueueScheduler,
+    events: Vec<(u64, Event<T, String>)>,
+) -> impl Observable<T, String>
+where
+    T: Sync + Send + 'static,
+{
+    let events = Arc::new(Mutex::new(Some(events)));
+    Create::new(move |observer: Box<dyn Observer<T, String>>| {
+        let observer = Arc::new(observer);
+        let mut disposals = Vec::new();
+        for (millis, event) in events.lock().unwrap().take().unwrap_or_default() {
+            let observer = observer.clone();
+            let disposal = scheduler.schedule(
+                move || observer.notify_if_unterminated(event),
+                Some(Duration::from_millis(millis)),
+            );
+            disposals.push(disposal);
+        }
+        Subscription::new(observer, move || drop(disposals))
+    })
+}